@@ -1,12 +1,105 @@
-use rand::Rng;
+use chrono::Local;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use ratatui::widgets::ListState;
-use std::{error, path::Path, vec};
+use serde::{Deserialize, Serialize};
+use std::{
+    error,
+    path::{Path, PathBuf},
+    time::Instant,
+    vec,
+};
 
-use crate::data::{self, Participant};
+use crate::branding::Branding;
+use crate::celebration::Celebration;
+use crate::data::{self, DataError, Participant, Prize, Winner};
+use crate::dedup::{self, Duplicate};
+use crate::elimination::Elimination;
+use crate::export::{self, ExportFormat};
+use crate::history::History;
+use crate::input::TextInput;
+use crate::search::Search;
+use crate::seed::Seed;
+use crate::session::Session;
+use crate::spin::{SpinConfig, Visual, MAX_ROTATIONS};
+use crate::styles;
+use crate::theme::{Theme, Themes};
+use crate::ui::HitAreas;
 
 /// Application result type.
 pub type AppResult<T> = std::result::Result<T, Box<dyn error::Error>>;
 
+/// How winners are treated in subsequent draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DrawMode {
+    /// Winners stay in the pool and can win again.
+    WithReplacement,
+    /// Winners are skipped by the spinner.
+    WithoutReplacement,
+}
+
+impl DrawMode {
+    pub fn toggle(self) -> Self {
+        match self {
+            DrawMode::WithReplacement => DrawMode::WithoutReplacement,
+            DrawMode::WithoutReplacement => DrawMode::WithReplacement,
+        }
+    }
+}
+
+impl std::fmt::Display for DrawMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DrawMode::WithReplacement => write!(f, "with replacement"),
+            DrawMode::WithoutReplacement => write!(f, "without replacement"),
+        }
+    }
+}
+
+/// How a single draw plays out. Both pick winners with the same odds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum DrawStyle {
+    /// The spinner runs through the list and lands on the winner.
+    Spin,
+    /// Everyone else is knocked out until one participant is left standing.
+    Elimination,
+}
+
+impl DrawStyle {
+    pub fn toggle(self) -> Self {
+        match self {
+            DrawStyle::Spin => DrawStyle::Elimination,
+            DrawStyle::Elimination => DrawStyle::Spin,
+        }
+    }
+}
+
+impl std::fmt::Display for DrawStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DrawStyle::Spin => write!(f, "spin"),
+            DrawStyle::Elimination => write!(f, "last one standing"),
+        }
+    }
+}
+
+/// What the keyboard is currently used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputMode {
+    /// Keys are commands.
+    Normal,
+    /// Typing the name of a new participant.
+    Add,
+    /// Typing a new name for the selected participant.
+    Rename,
+    /// Typing or pasting several names, one per line.
+    Paste,
+    /// Typing a search query that filters the list.
+    Search,
+    /// Reviewing possible duplicates before the draw.
+    Review,
+}
+
 /// Application.
 #[derive(Debug)]
 pub struct App {
@@ -18,98 +111,990 @@ pub struct App {
 
     // Lists
     pub all_participants: StatefulList<Participant>,
-    pub all_winners: Vec<Participant>,
+    pub all_winners: Vec<Winner>,
+
+    /// Prize catalog, given away from top to bottom. Empty for unnamed draws.
+    pub prizes: Vec<Prize>,
 
     // Spinner
+    pub draw_mode: DrawMode,
+    pub draw_style: DrawStyle,
+    /// The running or latest last-one-standing draw, until the list changes.
+    pub elimination: Option<Elimination>,
     pub is_spinning: bool,
+    pub spin_config: SpinConfig,
+    pub visual: Visual,
+    /// Themes to cycle through, and the name of the one in use.
+    pub themes: Themes,
+    pub theme: String,
+    pub branding: Branding,
+    /// Steps left before the spinner lands.
     pub spin_counter: usize,
+    /// Steps the current spin takes in total.
+    pub spin_steps: usize,
+    pub spin_started: Instant,
+    /// When the running spin was paused, if it is.
+    pub spin_paused: Option<Instant>,
     pub spin_winner: Option<Participant>,
-}
 
-impl Default for App {
-    fn default() -> Self {
-        Self::new(Path::new("participants.txt"))
-    }
+    /// Are winners celebrated with confetti and fireworks?
+    pub celebrate: bool,
+    /// Celebration of the latest winner, while it plays.
+    pub celebration: Option<Celebration>,
+
+    /// Where the results are written on export.
+    pub output: Option<PathBuf>,
+    /// Format of the results, guessed from the extension of `output` if not set.
+    pub output_format: Option<ExportFormat>,
+
+    /// Session file that is rewritten after every change, for crash recovery.
+    pub session_file: Option<PathBuf>,
+
+    // Participant editor
+    pub input_mode: InputMode,
+    pub input: TextInput,
+
+    /// Active search over the participant list, if any.
+    pub search: Option<Search>,
+
+    /// Possible duplicates waiting to be confirmed or dismissed.
+    pub review: Option<StatefulList<Duplicate>>,
+
+    /// Undo/redo stacks for removals, draws and resets.
+    pub history: History,
+
+    /// Short message shown in the Status pane.
+    pub status_message: Option<String>,
+
+    /// Is the full-screen presentation view shown instead of the tabs?
+    pub presenting: bool,
+
+    /// Clickable parts of the last frame, for mouse input.
+    pub hit_areas: HitAreas,
+
+    // Verifiable randomness
+    pub seed: Seed,
+    /// Commitment shown to the audience, fixed when the first draw starts.
+    pub published_commitment: Option<String>,
+    /// Is the session over and the seed shown? No more draws can be made once it is.
+    pub seed_revealed: bool,
+    pub(crate) rng: ChaCha20Rng,
 }
 
 impl App {
-    /// Constructs a new instance of [`App`].
-    pub fn new(path: &Path) -> Self {
-        let tab_titles = vec!["Home".to_string(), "Participants".to_string()];
+    /// Constructs a new instance of [`App`] with the participants listed in `path`.
+    pub fn new(path: &Path) -> Result<Self, DataError> {
+        let participants = data::read_participants_from_file(path)?;
+
+        Ok(Self::with_participants(participants))
+    }
+
+    /// Constructs a new instance of [`App`] from an already loaded list of participants.
+    pub fn with_participants(participants: Vec<Participant>) -> Self {
+        Self::with_seed(participants, Seed::random())
+    }
 
-        let participants = data::read_participants_from_file(path).expect("Failed to read file");
+    /// Constructs a new instance of [`App`] whose draws are fully determined by `seed`.
+    pub fn with_seed(participants: Vec<Participant>, seed: Seed) -> Self {
+        let tab_titles = vec!["Home".to_string(), "Participants".to_string()];
 
         Self {
             running: true,
             tabs: StatefulTabs::new(tab_titles),
             all_participants: StatefulList::new(participants),
             all_winners: Vec::new(),
+            prizes: Vec::new(),
+            draw_mode: DrawMode::WithoutReplacement,
+            draw_style: DrawStyle::Spin,
+            elimination: None,
             is_spinning: false,
+            spin_config: SpinConfig::default(),
+            visual: Visual::List,
+            themes: Themes::default(),
+            theme: "dark".to_string(),
+            branding: Branding::default(),
             spin_counter: 0,
+            spin_steps: 0,
+            spin_started: Instant::now(),
+            spin_paused: None,
             spin_winner: None,
+            celebrate: true,
+            celebration: None,
+            output: None,
+            output_format: None,
+            session_file: None,
+            input_mode: InputMode::Normal,
+            input: TextInput::default(),
+            search: None,
+            review: None,
+            history: History::default(),
+            status_message: None,
+            presenting: false,
+            hit_areas: HitAreas::default(),
+            seed,
+            published_commitment: None,
+            seed_revealed: false,
+            rng: seed.rng(),
         }
     }
 
     /// Set running to false to quit the application
     pub fn quit(&mut self) {
+        self.reveal_seed();
         self.running = false;
+        // Marks the session as ended, so the next run can start afresh.
+        self.save_session();
+    }
+
+    /// The commitment to the seed and participant list. Until the first draw it follows
+    /// changes to the list; after that it stays as published.
+    pub fn commitment(&self) -> String {
+        self.published_commitment
+            .clone()
+            .unwrap_or_else(|| self.seed.commitment(&self.all_participants.items))
+    }
+
+    /// Ends the session by showing the seed, so the draws can be checked.
+    ///
+    /// Any later draw could be predicted from the seed, so none are allowed after this.
+    pub fn reveal_seed(&mut self) {
+        if !self.seed_revealed && self.published_commitment.is_some() {
+            self.seed_revealed = true;
+            self.save_session();
+        }
+    }
+
+    /// Is anything moving on screen that needs frames at the full frame rate?
+    pub fn is_animating(&self) -> bool {
+        (self.is_spinning && self.spin_paused.is_none()) || self.celebration.is_some()
     }
 
     /// Handles the tick event of the terminal
     pub fn tick(&mut self) {
-        self.spin_round()
+        let now = Instant::now();
+
+        self.spin_round(now);
+        self.update_celebration(now);
+    }
+
+    /// Moves the celebration along, and drops it once it has played out.
+    fn update_celebration(&mut self, now: Instant) {
+        let Some(celebration) = &mut self.celebration else {
+            return;
+        };
+
+        celebration.update(now);
+        if celebration.is_finished() {
+            self.celebration = None;
+        }
+    }
+
+    /// Saves the session file, if there is one. Called after every change to the state.
+    pub fn save_session(&mut self) {
+        let Some(path) = &self.session_file else {
+            return;
+        };
+
+        if let Err(e) = Session::new(self).save(path) {
+            self.status_message = Some(format!("Could not save session: {}", e));
+        }
+    }
+
+    /// Remembers the current state so `action` can be undone.
+    ///
+    /// The crossed-out names of the last elimination are cleared, as the list is about to change.
+    /// A running elimination is kept, as it decides who wins the draw.
+    fn checkpoint(&mut self, action: String) {
+        self.history.record(action, Session::new(self));
+        if !self.is_spinning {
+            self.elimination = None;
+        }
+    }
+
+    /// Reverts the last removal, draw or reset.
+    pub fn undo(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.status_message = Some(match self.history.undo(Session::new(self)) {
+            Some((action, before)) => {
+                before.apply(self);
+                self.elimination = None;
+                self.save_session();
+                format!("Undid {}", action)
+            }
+            None => "Nothing to undo".to_string(),
+        });
+    }
+
+    /// Applies the last undone action again.
+    pub fn redo(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.status_message = Some(match self.history.redo(Session::new(self)) {
+            Some((action, after)) => {
+                after.apply(self);
+                self.elimination = None;
+                self.save_session();
+                format!("Redid {}", action)
+            }
+            None => "Nothing to redo".to_string(),
+        });
+    }
+
+    /// Opens the participant editor. Renaming starts from the selected participant.
+    pub fn start_input(&mut self, mode: InputMode) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.input = match mode {
+            InputMode::Rename => match self.all_participants.get_selected() {
+                Some(p) if p.tickets > 1 => TextInput::new(format!("{} x{}", p.name, p.tickets)),
+                Some(p) => TextInput::new(p.name),
+                None => return,
+            },
+            _ => TextInput::default(),
+        };
+        self.input_mode = mode;
+    }
+
+    /// Closes the participant editor without changing anything.
+    pub fn cancel_input(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input = TextInput::default();
+    }
+
+    /// Applies what was typed into the participant editor and closes it.
+    pub fn submit_input(&mut self) {
+        let mode = self.input_mode;
+        let text = std::mem::take(&mut self.input).value;
+        self.input_mode = InputMode::Normal;
+
+        let mut entries = parse_entries(&text);
+
+        match mode {
+            InputMode::Normal | InputMode::Search | InputMode::Review => {}
+            InputMode::Rename => {
+                let (Some(index), Some(renamed)) =
+                    (self.all_participants.state.selected(), entries.pop())
+                else {
+                    return;
+                };
+
+                let old_name = self.all_participants.items[index].name.clone();
+                self.checkpoint(format!("rename of {} to {}", old_name, renamed.name));
+                self.status_message = Some(format!("Renamed {} to {}", old_name, renamed.name));
+
+                let participant = &mut self.all_participants.items[index];
+                participant.name = renamed.name;
+                participant.tickets = renamed.tickets;
+
+                self.save_session();
+            }
+            InputMode::Add | InputMode::Paste => self.add_participants(entries),
+        }
+    }
+
+    /// Adds each non-empty line of pasted `text` as a participant.
+    pub fn paste_participants(&mut self, text: &str) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.add_participants(parse_entries(text));
+    }
+
+    /// Appends `entries` to the list and selects the first of them.
+    fn add_participants(&mut self, mut entries: Vec<Participant>) {
+        let added = match entries.as_slice() {
+            [] => return,
+            [participant] => participant.name.clone(),
+            _ => format!("{} participants", entries.len()),
+        };
+        self.checkpoint(format!("addition of {}", added));
+        self.status_message = Some(format!("Added {}", added));
+
+        let count = entries.len();
+        self.all_participants.items.append(&mut entries);
+        self.all_participants
+            .state
+            .select(Some(self.all_participants.items.len() - count));
+
+        self.save_session();
+    }
+
+    /// Looks for duplicate participants and opens the review screen if there are any.
+    pub fn start_review(&mut self) {
+        let duplicates = dedup::find_duplicates(&self.all_participants.items);
+        if duplicates.is_empty() {
+            return;
+        }
+
+        let mut review = StatefulList::new(duplicates);
+        review.next();
+
+        self.review = Some(review);
+        self.input_mode = InputMode::Review;
+    }
+
+    /// Flips whether the selected duplicate will be merged.
+    pub fn toggle_merge(&mut self) {
+        let Some(review) = &mut self.review else {
+            return;
+        };
+
+        if let Some(index) = review.state.selected() {
+            review.items[index].merge = !review.items[index].merge;
+        }
+    }
+
+    /// Merges the duplicates that were ticked and closes the review.
+    ///
+    /// The first entry is kept, with the highest ticket count of the ones merged into it.
+    pub fn confirm_review(&mut self) {
+        let Some(review) = self.review.take() else {
+            return;
+        };
+        self.input_mode = InputMode::Normal;
+
+        let merges: Vec<&Duplicate> = review.items.iter().filter(|d| d.merge).collect();
+        if merges.is_empty() {
+            return;
+        }
+
+        self.checkpoint(format!("merge of {} duplicates", merges.len()));
+        self.status_message = Some(format!("Merged {} duplicates", merges.len()));
+
+        dedup::merge(&mut self.all_participants.items, &merges);
+
+        self.all_participants.unselect();
+        self.save_session();
+    }
+
+    /// Closes the review without merging anyone.
+    pub fn skip_review(&mut self) {
+        self.review = None;
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Opens the search, remembering the selection to return to.
+    pub fn start_search(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.input_mode = InputMode::Search;
+        self.input = TextInput::default();
+        self.search = Some(Search {
+            previous_selection: self.all_participants.state.selected(),
+            matches: Vec::new(),
+        });
+        self.update_search();
+    }
+
+    /// Refilters the list after the query or the participants changed.
+    ///
+    /// The selection stays on the same participant if they still match, and moves to the best
+    /// match otherwise.
+    pub fn update_search(&mut self) {
+        let Some(search) = &mut self.search else {
+            return;
+        };
+
+        search.update(
+            &self.input.value,
+            self.all_participants.items.iter().map(|p| p.name.as_str()),
+        );
+
+        let selected = self.all_participants.state.selected();
+        if search.position(selected).is_none() {
+            let best = search.matches.first().map(|m| m.index);
+            self.all_participants.state.select(best);
+        }
+    }
+
+    /// Moves the selection to the next (or previous) match.
+    pub fn select_match(&mut self, forward: bool) {
+        let Some(search) = &self.search else {
+            return;
+        };
+
+        if self.is_spinning {
+            return;
+        }
+
+        let count = search.matches.len();
+        if count == 0 {
+            return;
+        }
+
+        let position = match search.position(self.all_participants.state.selected()) {
+            Some(position) if forward => (position + 1) % count,
+            Some(position) => (position + count - 1) % count,
+            None => 0,
+        };
+        self.all_participants
+            .state
+            .select(Some(search.matches[position].index));
+    }
+
+    /// Closes the search and keeps the selected match selected.
+    pub fn jump_to_match(&mut self) {
+        self.input_mode = InputMode::Normal;
+        self.input = TextInput::default();
+        self.search = None;
+    }
+
+    /// Clears the filter and goes back to the selection from before the search.
+    pub fn cancel_search(&mut self) {
+        if let Some(search) = self.search.take() {
+            self.all_participants
+                .state
+                .select(search.previous_selection);
+        }
+
+        self.input_mode = InputMode::Normal;
+        self.input = TextInput::default();
     }
 
+    /// Removes the selected match and keeps searching.
+    pub fn remove_match(&mut self) {
+        let Some(index) = self.all_participants.state.selected() else {
+            return;
+        };
+
+        self.remove_selected();
+
+        if let Some(search) = &mut self.search {
+            search.previous_selection = match search.previous_selection {
+                Some(previous) if previous == index => None,
+                Some(previous) if previous > index => Some(previous - 1),
+                previous => previous,
+            };
+        }
+        self.update_search();
+    }
+
+    /// Marks the selected participant as having won, or clears the mark.
+    ///
+    /// Marked participants are skipped when drawing without replacement.
+    pub fn toggle_winner_mark(&mut self) {
+        let Some(index) = self.all_participants.state.selected() else {
+            return;
+        };
+
+        if self.is_spinning {
+            return;
+        }
+
+        let participant = &self.all_participants.items[index];
+        let (action, marked) = match participant.is_winner {
+            true => (format!("unmarking of {}", participant.name), false),
+            false => (format!("marking of {}", participant.name), true),
+        };
+        self.checkpoint(action);
+
+        let participant = &mut self.all_participants.items[index];
+        participant.is_winner = marked;
+        self.status_message = Some(match marked {
+            true => format!("Marked {} as a winner", participant.name),
+            false => format!("Unmarked {}", participant.name),
+        });
+        self.save_session();
+    }
+
+    /// Switches to the next tab.
+    pub fn next_tab(&mut self) {
+        self.tabs.next_tab();
+        self.save_session();
+    }
+
+    /// Switches to the next way of showing the draw.
+    pub fn next_visual(&mut self) {
+        self.visual = self.visual.next();
+        self.status_message = Some(format!("Showing the draw as a {}", self.visual));
+    }
+
+    /// Draws everything in the colours of `theme` from now on.
+    pub fn set_theme(&mut self, theme: &Theme) {
+        styles::set_theme(theme);
+        self.theme = theme.name.clone();
+    }
+
+    /// Switches to the next theme.
+    pub fn next_theme(&mut self) {
+        let theme = self.themes.after(&self.theme).clone();
+        self.set_theme(&theme);
+        self.status_message = Some(format!("Theme: {}", self.theme));
+    }
+
+    /// Switches between the full-screen presentation view and the tabs.
+    pub fn toggle_presentation(&mut self) {
+        self.presenting = !self.presenting;
+    }
+
+    /// Switches to the tab at `index`.
+    pub fn select_tab(&mut self, index: usize) {
+        if index < self.tabs.titles.len() {
+            self.tabs.active = index;
+            self.save_session();
+        }
+    }
+
+    /// Selects the participant at `index`. The selection is left alone during a spin.
+    pub fn select_participant(&mut self, index: usize) {
+        if self.is_spinning || index >= self.all_participants.items.len() {
+            return;
+        }
+
+        self.all_participants.state.select(Some(index));
+    }
+
+    /// Removes the selected participant.
+    pub fn remove_selected(&mut self) {
+        let Some(participant) = self.all_participants.get_selected() else {
+            return;
+        };
+
+        if self.is_spinning {
+            return;
+        }
+
+        self.checkpoint(format!("removal of {}", participant.name));
+        self.all_participants.remove();
+        self.save_session();
+    }
+
+    /// Writes the results of the session to [`App::output`], or `raffle-results.md`.
+    pub fn export_results(&mut self) {
+        let path = self
+            .output
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("raffle-results.md"));
+        let format = self.export_format(&path);
+
+        // Exporting doesn't end the session, so the seed stays hidden until it does.
+        self.status_message = Some(match export::write(self, &path, format) {
+            Ok(()) => format!("Exported results to {}", path.display()),
+            Err(e) => format!("Export failed: {}", e),
+        });
+    }
+
+    /// Format to write the results to `path` in.
+    pub fn export_format(&self, path: &Path) -> ExportFormat {
+        self.output_format
+            .unwrap_or_else(|| ExportFormat::from_path(path))
+    }
+
+    /// Switches between drawing with and without replacement.
+    pub fn toggle_draw_mode(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.draw_mode = self.draw_mode.toggle();
+        self.save_session();
+    }
+
+    /// Can the participant win the next draw?
+    pub fn is_eligible(&self, participant: &Participant) -> bool {
+        match self.draw_mode {
+            DrawMode::WithReplacement => true,
+            DrawMode::WithoutReplacement => !participant.is_winner,
+        }
+    }
+
+    /// Number of participants that can win the next draw.
+    pub fn eligible_count(&self) -> usize {
+        self.all_participants
+            .items
+            .iter()
+            .filter(|p| self.is_eligible(p))
+            .count()
+    }
+
+    /// Number of tickets held by participants that can win the next draw.
+    pub fn eligible_tickets(&self) -> u64 {
+        self.all_participants
+            .items
+            .iter()
+            .filter(|p| self.is_eligible(p))
+            .map(|p| u64::from(p.tickets))
+            .sum()
+    }
+
+    /// Probability that `participant` wins the next draw.
+    pub fn chance(&self, participant: &Participant) -> f32 {
+        match self.eligible_tickets() {
+            0 => 0.0,
+            _ if !self.is_eligible(participant) => 0.0,
+            tickets => participant.tickets as f32 / tickets as f32,
+        }
+    }
+
+    /// Position among the eligible participants of whoever holds the `ticket`-th ticket.
+    ///
+    /// Tickets are numbered from zero, in list order.
+    pub fn eligible_holding_ticket(&self, ticket: u64) -> usize {
+        let mut remaining = ticket;
+
+        for (position, participant) in self
+            .all_participants
+            .items
+            .iter()
+            .filter(|p| self.is_eligible(p))
+            .enumerate()
+        {
+            let tickets = u64::from(participant.tickets);
+            if remaining < tickets {
+                return position;
+            }
+            remaining -= tickets;
+        }
+
+        unreachable!("ticket {} is out of range", ticket)
+    }
+
+    /// The prize the next draw is for, if a catalog is loaded.
+    pub fn current_prize(&self) -> Option<&Prize> {
+        self.current_prize_index().map(|index| &self.prizes[index])
+    }
+
+    /// Position in the catalog of the prize the next draw is for.
+    ///
+    /// Awards are counted per catalog line, so a prize listed twice is given away twice.
+    fn current_prize_index(&self) -> Option<usize> {
+        (0..self.prizes.len()).find(|&index| {
+            let awarded = self
+                .all_winners
+                .iter()
+                .filter(|w| w.prize_index == Some(index))
+                .count();
+
+            awarded < self.prizes[index].quantity
+        })
+    }
+
+    /// Has every prize in the catalog been given away?
+    pub fn prizes_exhausted(&self) -> bool {
+        !self.prizes.is_empty() && self.current_prize().is_none()
+    }
+
+    /// Starts a spin that lands on a participant chosen by the seeded generator.
+    ///
+    /// The landing spot doesn't depend on where the selection starts, so replaying the same
+    /// seed against the same list always gives the same winners.
     pub fn start_spin(&mut self) {
-        if self.all_participants.items.is_empty() {
+        let participant_count = self.eligible_count();
+        let ticket_count = self.eligible_tickets();
+
+        if self.seed_revealed {
+            self.status_message =
+                Some("The seed is revealed, start a new session to draw again".to_string());
+            return;
+        }
+
+        // Starting over mid-spin would draw a fresh ticket and re-roll the winner.
+        if self.is_spinning
+            || participant_count == 0
+            || ticket_count == 0
+            || self.prizes_exhausted()
+        {
             return;
         }
 
-        let participant_count = self.all_participants.items.len();
+        // Undoing a draw also rewinds the random stream, so it can't be used to re-roll.
+        self.checkpoint("spin".to_string());
 
-        let min_spins = participant_count * 3;
-        let max_spins = participant_count * 6;
+        // From the first draw on, the list shown to the audience is locked into the commitment.
+        if self.published_commitment.is_none() {
+            self.published_commitment = Some(self.commitment());
+        }
 
-        let mut rng = rand::thread_rng();
-        let random_spins = rng.gen_range(min_spins..max_spins);
+        let ticket = self.rng.gen_range(0..ticket_count);
+        let target = self.eligible_holding_ticket(ticket);
+        let min_rotations = self.spin_config.min_rotations.min(MAX_ROTATIONS);
+        let rotations = self.rng.gen_range(min_rotations..min_rotations + 3);
 
-        self.spin_counter = random_spins;
+        match self.draw_style {
+            DrawStyle::Spin => {
+                self.spin_steps = rotations
+                    .saturating_mul(participant_count)
+                    .saturating_add(self.steps_to_eligible(target));
+            }
+            DrawStyle::Elimination => {
+                self.spin_steps = 0;
+                self.start_elimination(target);
+            }
+        }
+        self.spin_counter = self.spin_steps;
+        self.spin_started = Instant::now();
         self.spin_winner = None;
+        self.celebration = None;
         self.is_spinning = true;
     }
 
-    pub fn spin_round(&mut self) {
+    /// Plans the knock-outs that leave the `target`-th eligible participant standing.
+    ///
+    /// The order comes from its own generator, derived from the seed, so the main random
+    /// stream is used exactly as by a spin and `raffle verify` replays either style.
+    fn start_elimination(&mut self, target: usize) {
+        let eligible: Vec<(usize, u32)> = self
+            .all_participants
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, p)| self.is_eligible(p))
+            .map(|(i, p)| (i, p.tickets))
+            .collect();
+        let winner = eligible[target].0;
+        let others = eligible.into_iter().filter(|&(i, _)| i != winner).collect();
+
+        let seed = self
+            .seed
+            .derive("elimination", self.all_winners.len() as u64);
+        self.elimination = Some(Elimination::new(
+            winner,
+            others,
+            self.spin_config.duration,
+            &mut ChaCha20Rng::seed_from_u64(seed),
+        ));
+
+        // Nobody is highlighted until the last one is standing.
+        self.all_participants.state.select(None);
+    }
+
+    /// Is the participant at `index` crossed out by the running or latest elimination?
+    pub fn is_knocked_out(&self, index: usize) -> bool {
+        self.elimination
+            .as_ref()
+            .is_some_and(|elimination| elimination.is_knocked_out(index))
+    }
+
+    /// Participants left in a running elimination.
+    pub fn still_standing(&self) -> Option<usize> {
+        match (&self.elimination, self.is_spinning) {
+            (Some(elimination), true) => Some(elimination.still_standing()),
+            _ => None,
+        }
+    }
+
+    /// Switches between spins and last-one-standing draws.
+    pub fn toggle_draw_style(&mut self) {
+        if self.is_spinning {
+            return;
+        }
+
+        self.draw_style = self.draw_style.toggle();
+        self.status_message = Some(format!("Next draw: {}", self.draw_style));
+    }
+
+    /// Runs a whole spin without waiting for it to play out. Returns `None` when nothing can
+    /// be drawn.
+    pub fn draw_to_completion(&mut self) -> Option<Participant> {
+        self.start_spin();
         if !self.is_spinning {
+            return None;
+        }
+        self.spin_round(self.spin_started + self.spin_config.duration);
+        self.spin_winner.clone()
+    }
+
+    /// Number of spinner steps from the current selection to the `target`-th eligible participant.
+    fn steps_to_eligible(&self, target: usize) -> usize {
+        let participant_count = self.eligible_count();
+
+        // Eligible participants at or before the selection; the next step lands just after them.
+        let passed = match self.all_participants.state.selected() {
+            Some(selected) => self.all_participants.items[..=selected]
+                .iter()
+                .filter(|p| self.is_eligible(p))
+                .count(),
+            None => 0,
+        };
+
+        (target + participant_count - passed % participant_count) % participant_count + 1
+    }
+
+    /// Moves the spinner as far as the easing curve says it should be at `now`, and records
+    /// the winner once the spin is over.
+    pub fn spin_round(&mut self, now: Instant) {
+        if !self.is_spinning || self.spin_paused.is_some() {
             return;
         }
 
-        if self.spin_counter > 0 {
-            self.all_participants.next();
+        let elapsed = now.saturating_duration_since(self.spin_started);
+
+        if let Some(elimination) = &mut self.elimination {
+            if !elimination.advance(elapsed) {
+                return;
+            }
+
+            let winner = elimination.winner;
+            self.all_participants.state.select(Some(winner));
+            self.record_winner(now);
+            return;
+        }
+
+        let steps_due = self.spin_config.steps_due(self.spin_steps, elapsed);
+
+        while self.spin_steps - self.spin_counter < steps_due {
+            self.advance_spinner();
             self.spin_counter -= 1;
+        }
+
+        if self.spin_counter > 0 || elapsed < self.spin_config.duration {
             return;
         }
 
-        if let Some(winner) = &mut self.all_participants.get_selected() {
+        self.record_winner(now);
+    }
+
+    /// Marks the selected participant as the winner of the current prize and ends the draw.
+    fn record_winner(&mut self, now: Instant) {
+        if let Some(index) = self.all_participants.state.selected() {
+            let winner = &mut self.all_participants.items[index];
             winner.is_winner = true;
 
+            let winner = winner.clone();
+            let prize_index = self.current_prize_index();
+            let prize = self.current_prize().cloned();
+
+            self.history
+                .relabel_last(format!("draw of {}", winner.name));
             self.spin_winner = Some(winner.clone());
-            self.all_winners.push(winner.clone());
+
+            if self.celebrate {
+                let seed = self
+                    .seed
+                    .derive("celebration", self.all_winners.len() as u64);
+                let mut celebration = Celebration::new(seed);
+                celebration.started = now;
+                self.celebration = Some(celebration);
+            }
+
+            let eliminated = self
+                .elimination
+                .iter()
+                .flat_map(|elimination| &elimination.order)
+                .map(|&i| self.all_participants.items[i].name.clone())
+                .collect();
+
+            self.all_winners.push(Winner {
+                participant: winner,
+                prize,
+                prize_index,
+                drawn_at: Local::now(),
+                eliminated,
+            });
 
             self.stop_spin();
+
+            // With the last prize given away the session is over.
+            if self.prizes_exhausted() {
+                self.reveal_seed();
+            }
+            self.save_session();
+        }
+    }
+
+    /// Participants shown on the wheel and reel visuals: everyone who can win, and the winner
+    /// until the next spin.
+    pub fn drawable(&self) -> Vec<usize> {
+        let selected = self.all_participants.state.selected();
+
+        (0..self.all_participants.items.len())
+            .filter(|&i| {
+                (self.is_eligible(&self.all_participants.items[i]) && !self.is_knocked_out(i))
+                    || (self.spin_winner.is_some() && Some(i) == selected)
+            })
+            .collect()
+    }
+
+    /// How far the spinner has moved from the selected participant towards the next one,
+    /// from 0 to 1. Used to animate visuals smoothly between steps.
+    pub fn spin_fraction(&self, now: Instant) -> f64 {
+        if !self.is_spinning {
+            return 0.0;
+        }
+
+        let now = self.spin_paused.unwrap_or(now);
+        let elapsed = now.saturating_duration_since(self.spin_started);
+        let taken = (self.spin_steps - self.spin_counter) as f64;
+
+        (self.spin_config.position(self.spin_steps, elapsed) - taken).clamp(0.0, 1.0)
+    }
+
+    /// Moves the selection to the next participant that can win, skipping the others.
+    fn advance_spinner(&mut self) {
+        for _ in 0..self.all_participants.items.len() {
+            self.all_participants.next();
+
+            match self.all_participants.get_selected() {
+                Some(participant) if !self.is_eligible(&participant) => continue,
+                _ => return,
+            }
+        }
+    }
+
+    /// Holds the spin where it is, for example while the terminal is out of focus.
+    pub fn pause_spin(&mut self) {
+        if self.is_spinning && self.spin_paused.is_none() {
+            self.spin_paused = Some(Instant::now());
+            self.status_message = Some("Spin paused".to_string());
+        }
+    }
+
+    /// Carries on with a paused spin from where it was held.
+    pub fn resume_spin(&mut self) {
+        if let Some(paused) = self.spin_paused.take() {
+            self.spin_started += paused.elapsed();
+            self.status_message = None;
         }
     }
 
     pub fn stop_spin(&mut self) {
         self.is_spinning = false;
+        self.spin_paused = None;
     }
 
+    /// Clears the winner of the last draw.
+    ///
+    /// A running draw can't be reset: its ticket has already been taken from the random
+    /// stream, so starting again would re-roll the winner. Undoing the finished draw rewinds
+    /// the stream with it.
     pub fn reset_spin(&mut self) {
+        if self.is_spinning {
+            self.status_message = Some("Wait for the draw to finish before resetting".to_string());
+            return;
+        }
+
+        if self.spin_winner.is_some() {
+            self.checkpoint("reset".to_string());
+        }
+
         self.stop_spin();
         self.spin_counter = 0;
         self.spin_winner = None;
+        self.celebration = None;
+        self.elimination = None;
+        self.save_session();
     }
 }
 
+/// Parses one participant per non-empty line of `text`.
+fn parse_entries(text: &str) -> Vec<Participant> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(Participant::parse)
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct StatefulTabs {
     pub titles: Vec<String>,
@@ -200,7 +1185,9 @@ impl<T: std::clone::Clone> StatefulList<T> {
             return;
         }
 
-        let Some(i) = self.state.selected() else { return };
+        let Some(i) = self.state.selected() else {
+            return;
+        };
 
         self.items.remove(i);
         self.state.select(None);
@@ -0,0 +1,298 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Paragraph, Widget, Wrap},
+};
+use unicode_normalization::UnicodeNormalization;
+
+/// Pixel rows in every glyph.
+pub const HEIGHT: usize = 5;
+
+/// Block-letter glyphs, drawn with `#` for a filled pixel.
+const GLYPHS: &[(char, [&str; HEIGHT])] = &[
+    (' ', ["   ", "   ", "   ", "   ", "   "]),
+    ('A', [" ### ", "#   #", "#####", "#   #", "#   #"]),
+    ('B', ["#### ", "#   #", "#### ", "#   #", "#### "]),
+    ('C', [" ####", "#    ", "#    ", "#    ", " ####"]),
+    ('D', ["#### ", "#   #", "#   #", "#   #", "#### "]),
+    ('E', ["#####", "#    ", "#### ", "#    ", "#####"]),
+    ('F', ["#####", "#    ", "#### ", "#    ", "#    "]),
+    ('G', [" ####", "#    ", "#  ##", "#   #", " ####"]),
+    ('H', ["#   #", "#   #", "#####", "#   #", "#   #"]),
+    ('I', ["###", " # ", " # ", " # ", "###"]),
+    ('J', ["  ###", "    #", "    #", "#   #", " ### "]),
+    ('K', ["#   #", "#  # ", "###  ", "#  # ", "#   #"]),
+    ('L', ["#    ", "#    ", "#    ", "#    ", "#####"]),
+    ('M', ["#   #", "## ##", "# # #", "#   #", "#   #"]),
+    ('N', ["#   #", "##  #", "# # #", "#  ##", "#   #"]),
+    ('O', [" ### ", "#   #", "#   #", "#   #", " ### "]),
+    ('P', ["#### ", "#   #", "#### ", "#    ", "#    "]),
+    ('Q', [" ### ", "#   #", "# # #", "#  # ", " ## #"]),
+    ('R', ["#### ", "#   #", "#### ", "#  # ", "#   #"]),
+    ('S', [" ####", "#    ", " ### ", "    #", "#### "]),
+    ('T', ["#####", "  #  ", "  #  ", "  #  ", "  #  "]),
+    ('U', ["#   #", "#   #", "#   #", "#   #", " ### "]),
+    ('V', ["#   #", "#   #", "#   #", " # # ", "  #  "]),
+    ('W', ["#   #", "#   #", "# # #", "## ##", "#   #"]),
+    ('X', ["#   #", " # # ", "  #  ", " # # ", "#   #"]),
+    ('Y', ["#   #", " # # ", "  #  ", "  #  ", "  #  "]),
+    ('Z', ["#####", "   # ", "  #  ", " #   ", "#####"]),
+    ('Æ', [" ######", "#  #   ", "###### ", "#  #   ", "#  ####"]),
+    ('Ø', [" ####", "#  ##", "# # #", "##  #", "#### "]),
+    ('Å', ["  #  ", " ### ", "#   #", "#####", "#   #"]),
+    ('0', [" ## ", "#  #", "#  #", "#  #", " ## "]),
+    ('1', [" # ", "## ", " # ", " # ", "###"]),
+    ('2', [" ### ", "#   #", "  ## ", " #   ", "#####"]),
+    ('3', ["#### ", "    #", " ### ", "    #", "#### "]),
+    ('4', ["#   #", "#   #", "#####", "    #", "    #"]),
+    ('5', ["#####", "#    ", "#### ", "    #", "#### "]),
+    ('6', [" ### ", "#    ", "#### ", "#   #", " ### "]),
+    ('7', ["#####", "    #", "   # ", "  #  ", "  #  "]),
+    ('8', [" ### ", "#   #", " ### ", "#   #", " ### "]),
+    ('9', [" ### ", "#   #", " ####", "    #", " ### "]),
+    ('-', ["   ", "   ", "###", "   ", "   "]),
+    ('.', [" ", " ", " ", " ", "#"]),
+    (',', ["  ", "  ", "  ", " #", "# "]),
+    ('\'', ["#", "#", " ", " ", " "]),
+    ('!', ["#", "#", "#", " ", "#"]),
+    ('?', [" ### ", "#   #", "  ## ", "     ", "  #  "]),
+];
+
+/// How big the block letters are drawn, from biggest to smallest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Size {
+    /// Four columns and two rows per pixel.
+    Huge,
+    /// Two columns per pixel, so the pixels look square.
+    Large,
+    /// One column per pixel.
+    Medium,
+    /// One column and half a row per pixel, using half blocks.
+    Small,
+}
+
+impl Size {
+    /// All sizes, biggest first.
+    pub const ALL: [Size; 4] = [Size::Huge, Size::Large, Size::Medium, Size::Small];
+
+    /// Columns taken by one pixel.
+    fn columns(self) -> usize {
+        match self {
+            Size::Huge => 4,
+            Size::Large => 2,
+            Size::Medium | Size::Small => 1,
+        }
+    }
+
+    /// Rows taken by one line of letters.
+    pub fn height(self) -> usize {
+        match self {
+            Size::Huge => HEIGHT * 2,
+            Size::Large | Size::Medium => HEIGHT,
+            Size::Small => HEIGHT.div_ceil(2),
+        }
+    }
+}
+
+/// The glyph for `c`, ignoring case.
+///
+/// Accented letters without a glyph of their own are drawn without the accent, and anything
+/// else is shown as `?`.
+fn glyph(c: char) -> &'static [&'static str; HEIGHT] {
+    let find = |c: char| {
+        GLYPHS
+            .iter()
+            .find(|(glyph, _)| *glyph == c)
+            .map(|(_, rows)| rows)
+    };
+    let c = c.to_uppercase().next().unwrap_or(c);
+
+    find(c)
+        .or_else(|| c.nfd().next().and_then(find))
+        .or_else(|| find('?'))
+        .expect("the font has a `?` glyph")
+}
+
+/// The pixel rows of `text` on a single line, with a blank pixel between letters.
+fn pixels(text: &str) -> [String; HEIGHT] {
+    let mut rows: [String; HEIGHT] = Default::default();
+
+    for (i, c) in text.chars().enumerate() {
+        for (row, glyph_row) in rows.iter_mut().zip(glyph(c)) {
+            if i > 0 {
+                row.push(' ');
+            }
+            row.push_str(glyph_row);
+        }
+    }
+
+    rows
+}
+
+/// Renders `text` on a single line of block letters at `size`.
+pub fn render(text: &str, size: Size) -> Vec<String> {
+    let rows = pixels(text);
+    let filled = |row: usize, column: usize| {
+        rows.get(row).and_then(|r| r.as_bytes().get(column)) == Some(&b'#')
+    };
+    let width = rows[0].len();
+
+    match size {
+        Size::Small => (0..size.height())
+            .map(|line| {
+                (0..width)
+                    .map(
+                        |column| match (filled(line * 2, column), filled(line * 2 + 1, column)) {
+                            (true, true) => '█',
+                            (true, false) => '▀',
+                            (false, true) => '▄',
+                            (false, false) => ' ',
+                        },
+                    )
+                    .collect()
+            })
+            .collect(),
+        _ => {
+            let repeat = size.height() / HEIGHT;
+
+            rows.iter()
+                .flat_map(|row| {
+                    let line: String = row
+                        .chars()
+                        .map(|pixel| match pixel {
+                            '#' => "█".repeat(size.columns()),
+                            _ => " ".repeat(size.columns()),
+                        })
+                        .collect();
+                    std::iter::repeat_n(line, repeat)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Width in columns of `text` on a single line of block letters at `size`.
+pub fn width(text: &str, size: Size) -> usize {
+    pixels(text)[0].chars().count() * size.columns()
+}
+
+/// Renders `text` at the biggest size that fits in `width` × `height`, wrapping between words
+/// where needed. Wrapped lines are separated by a blank row.
+///
+/// Returns `None` when even the smallest size doesn't fit.
+pub fn fit(text: &str, width: usize, height: usize) -> Option<Vec<String>> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.is_empty() {
+        return Some(Vec::new());
+    }
+
+    Size::ALL.into_iter().find_map(|size| {
+        let lines = wrap(&words, width, size)?;
+
+        let rows = lines.len() * (size.height() + 1) - 1;
+        if rows > height {
+            return None;
+        }
+
+        let mut rendered = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            if i > 0 {
+                rendered.push(String::new());
+            }
+            rendered.extend(render(line, size));
+        }
+        Some(rendered)
+    })
+}
+
+/// Packs `words` greedily into lines no wider than `width` at `size`.
+fn wrap(words: &[&str], width: usize, size: Size) -> Option<Vec<String>> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for word in words {
+        if self::width(word, size) > width {
+            return None;
+        }
+
+        match lines.last_mut() {
+            Some(line) if self::width(&format!("{} {}", line, word), size) <= width => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+
+    Some(lines)
+}
+
+/// Text in block letters, as big as fits the area, centred in it.
+///
+/// Falls back to plain, wrapped text when even the smallest letters don't fit.
+#[derive(Debug, Clone)]
+pub struct BigText<'a> {
+    text: &'a str,
+    style: Style,
+}
+
+impl<'a> BigText<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            style: Style::default(),
+        }
+    }
+
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Rows the text takes when rendered at most `max_height` rows high and `width` wide.
+    pub fn height(&self, width: u16, max_height: u16) -> u16 {
+        match fit(self.text, width as usize, max_height as usize) {
+            Some(lines) => lines.len() as u16,
+            None => self.plain_height(width).min(max_height),
+        }
+    }
+
+    /// Rows the text takes as plain text wrapped at `width`.
+    fn plain_height(&self, width: u16) -> u16 {
+        (Span::raw(self.text).width() as u16).div_ceil(width.max(1))
+    }
+}
+
+impl Widget for BigText<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let (height, text) = match fit(self.text, area.width as usize, area.height as usize) {
+            Some(lines) => (
+                lines.len() as u16,
+                Text::from(
+                    lines
+                        .into_iter()
+                        .map(|line| Line::from(Span::styled(line, self.style)))
+                        .collect::<Vec<_>>(),
+                ),
+            ),
+            None => (
+                self.plain_height(area.width),
+                Text::from(Span::styled(self.text, self.style)),
+            ),
+        };
+        let height = height.min(area.height);
+
+        Paragraph::new(text)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: false })
+            .render(
+                Rect {
+                    y: area.y + (area.height - height) / 2,
+                    height,
+                    ..area
+                },
+                buf,
+            );
+    }
+}
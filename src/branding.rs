@@ -0,0 +1,102 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::app::AppResult;
+use crate::styles;
+use crate::theme::ColorValue;
+
+/// Who the raffle is for, as shown on the Home tab.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Branding {
+    pub event_name: String,
+    pub tagline: String,
+    pub organiser: Option<String>,
+    pub date: Option<String>,
+    /// Shown in place of the R.A.F.F.L.E. logo.
+    pub logo: String,
+    /// Shown under the event details when there is room.
+    pub mascot: Option<String>,
+    /// Colour of the event name, instead of the theme's.
+    pub accent: Option<Color>,
+}
+
+impl Default for Branding {
+    /// The Copenhagen Rust Community, which the app was made for.
+    fn default() -> Self {
+        Self {
+            event_name: "Copenhagen Rust Community 🦀🧡".to_string(),
+            tagline: "Rapidly Assembled Ferris Fortune Locator Engine".to_string(),
+            organiser: None,
+            date: None,
+            logo: styles::LOGO.to_string(),
+            mascot: Some(styles::FERRIS.to_string()),
+            accent: None,
+        }
+    }
+}
+
+/// A branding file. Anything left out keeps the default, except that the mascot is plain
+/// Ferris, without the Copenhagen speech bubble.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BrandingFile {
+    event_name: Option<String>,
+    tagline: Option<String>,
+    organiser: Option<String>,
+    date: Option<String>,
+    logo_file: Option<PathBuf>,
+    mascot_file: Option<MascotFile>,
+    accent: Option<ColorValue>,
+}
+
+/// The `mascot_file` setting: a path, or `false` or `""` for no mascot.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum MascotFile {
+    Shown(bool),
+    Path(PathBuf),
+}
+
+impl Branding {
+    /// Reads the TOML branding file at `path`.
+    pub fn load(path: &Path) -> AppResult<Self> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        let dir = path.parent().unwrap_or(Path::new(""));
+
+        Self::parse(&contents, dir).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    /// Reads branding from `toml`. The logo and mascot files are looked up relative to `dir`.
+    pub fn parse(toml: &str, dir: &Path) -> AppResult<Self> {
+        let file: BrandingFile = toml::from_str(toml)?;
+        let default = Self::default();
+
+        let read_art = |path: &Path| {
+            let path = dir.join(path);
+            fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))
+        };
+
+        Ok(Self {
+            event_name: file.event_name.unwrap_or(default.event_name),
+            tagline: file.tagline.unwrap_or(default.tagline),
+            organiser: file.organiser,
+            date: file.date,
+            logo: match &file.logo_file {
+                Some(path) => read_art(path)?,
+                None => default.logo,
+            },
+            mascot: match &file.mascot_file {
+                None | Some(MascotFile::Shown(true)) => Some(styles::CRAB.to_string()),
+                Some(MascotFile::Shown(false)) => None,
+                Some(MascotFile::Path(path)) if path.as_os_str().is_empty() => None,
+                Some(MascotFile::Path(path)) => Some(read_art(path)?),
+            },
+            accent: file.accent.as_ref().map(ColorValue::parse).transpose()?,
+        })
+    }
+}
@@ -0,0 +1,219 @@
+use crate::styles;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use ratatui::{buffer::Buffer, layout::Rect, style::Style, widgets::Widget};
+use std::time::{Duration, Instant};
+
+/// How long a celebration lasts.
+pub const DURATION: Duration = Duration::from_secs(4);
+
+/// Length of one simulation step. The simulation always moves in whole steps, so the same
+/// seed gives the same picture at the same moment however often it is updated.
+const STEP: f32 = 1.0 / 30.0;
+
+/// Pieces of confetti dropped at the start.
+const CONFETTI: usize = 90;
+
+/// Fireworks set off during a celebration.
+const BURSTS: usize = 4;
+
+/// Sparks in a single firework.
+const SPARKS: usize = 28;
+
+/// How long a spark glows, in seconds.
+const SPARK_LIFETIME: f32 = 1.2;
+
+/// Downward pull on sparks, in screen heights per second squared.
+const GRAVITY: f32 = 0.35;
+
+/// Number of colours in the [`palette`].
+const PALETTE_SIZE: usize = 4;
+
+const CONFETTI_SYMBOLS: [&str; 6] = ["▪", "▮", "•", "◆", "*", "✦"];
+
+/// A single piece of confetti or firework spark.
+///
+/// Positions are fractions of the area, from the top left, so the same celebration can be
+/// drawn at any size.
+#[derive(Debug, Clone)]
+struct Particle {
+    kind: Kind,
+    x: f32,
+    y: f32,
+    /// Speed in area widths and heights per second.
+    dx: f32,
+    dy: f32,
+    /// Seconds since the particle appeared.
+    age: f32,
+    colour: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    /// Falls and sways from side to side.
+    Confetti { symbol: usize, sway: f32 },
+    /// Flies out of a firework and fades.
+    Spark,
+}
+
+/// A firework that goes off at a set time.
+#[derive(Debug, Clone)]
+struct Burst {
+    at: f32,
+    x: f32,
+    y: f32,
+}
+
+/// Falling confetti and fireworks shown for a few seconds after a winner is drawn.
+#[derive(Debug, Clone)]
+pub struct Celebration {
+    /// When the celebration started, for [`update`](Celebration::update).
+    pub started: Instant,
+    rng: ChaCha8Rng,
+    particles: Vec<Particle>,
+    /// Fireworks that haven't gone off yet, soonest last.
+    bursts: Vec<Burst>,
+    /// Simulation steps taken so far.
+    steps: u32,
+}
+
+impl Celebration {
+    /// Starts a celebration whose every particle is determined by `seed`.
+    pub fn new(seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let particles = (0..CONFETTI)
+            .map(|_| Particle {
+                kind: Kind::Confetti {
+                    symbol: rng.gen_range(0..CONFETTI_SYMBOLS.len()),
+                    sway: rng.gen_range(0.0..std::f32::consts::TAU),
+                },
+                x: rng.gen_range(0.0..1.0),
+                y: rng.gen_range(-0.6..0.0),
+                dx: 0.0,
+                dy: rng.gen_range(0.15..0.35),
+                age: 0.0,
+                colour: rng.gen_range(0..PALETTE_SIZE),
+            })
+            .collect();
+
+        let mut bursts: Vec<Burst> = (0..BURSTS)
+            .map(|i| Burst {
+                at: 0.2 + i as f32 * 0.7 + rng.gen_range(0.0..0.3),
+                x: rng.gen_range(0.2..0.8),
+                y: rng.gen_range(0.15..0.45),
+            })
+            .collect();
+        bursts.reverse();
+
+        Self {
+            started: Instant::now(),
+            rng,
+            particles,
+            bursts,
+            steps: 0,
+        }
+    }
+
+    /// Brings the animation up to `now`.
+    pub fn update(&mut self, now: Instant) {
+        self.advance_to(now.saturating_duration_since(self.started));
+    }
+
+    /// Runs the simulation up to `elapsed` after the start.
+    pub fn advance_to(&mut self, elapsed: Duration) {
+        let elapsed = elapsed.min(DURATION).as_secs_f32();
+
+        while (self.steps + 1) as f32 * STEP <= elapsed {
+            self.step();
+        }
+    }
+
+    /// Has the celebration played out?
+    pub fn is_finished(&self) -> bool {
+        (self.steps + 1) as f32 * STEP > DURATION.as_secs_f32()
+    }
+
+    fn step(&mut self) {
+        self.steps += 1;
+        let time = self.steps as f32 * STEP;
+
+        while self.bursts.last().is_some_and(|burst| burst.at <= time) {
+            let burst = self.bursts.pop().expect("checked above");
+            let colour = self.rng.gen_range(0..PALETTE_SIZE);
+
+            for i in 0..SPARKS {
+                let angle = i as f32 / SPARKS as f32 * std::f32::consts::TAU
+                    + self.rng.gen_range(-0.1..0.1);
+                let speed = self.rng.gen_range(0.15..0.3);
+
+                self.particles.push(Particle {
+                    kind: Kind::Spark,
+                    x: burst.x,
+                    y: burst.y,
+                    // Terminal cells are about twice as tall as they are wide.
+                    dx: angle.cos() * speed * 0.6,
+                    dy: angle.sin() * speed,
+                    age: 0.0,
+                    colour,
+                });
+            }
+        }
+
+        for particle in &mut self.particles {
+            particle.age += STEP;
+
+            match particle.kind {
+                Kind::Confetti { sway, .. } => {
+                    particle.dx = (sway + particle.age * 3.0).sin() * 0.05;
+                }
+                Kind::Spark => {
+                    particle.dy += GRAVITY * STEP;
+                }
+            }
+
+            particle.x += particle.dx * STEP;
+            particle.y += particle.dy * STEP;
+        }
+
+        self.particles.retain(|particle| match particle.kind {
+            Kind::Confetti { .. } => particle.y < 1.0,
+            Kind::Spark => particle.age < SPARK_LIFETIME,
+        });
+    }
+}
+
+/// Colour of a particle.
+fn palette(colour: usize) -> Style {
+    match colour {
+        0 => styles::winner(),
+        1 => styles::orange(),
+        2 => styles::spin(),
+        _ => styles::action(),
+    }
+}
+
+impl Widget for &Celebration {
+    /// Draws the particles on top of whatever is already in `area`.
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        for particle in &self.particles {
+            if !(0.0..1.0).contains(&particle.x) || !(0.0..1.0).contains(&particle.y) {
+                continue;
+            }
+
+            let x = area.x + (particle.x * area.width as f32) as u16;
+            let y = area.y + (particle.y * area.height as f32) as u16;
+
+            let symbol = match particle.kind {
+                Kind::Confetti { symbol, .. } => CONFETTI_SYMBOLS[symbol],
+                Kind::Spark if particle.age < SPARK_LIFETIME / 3.0 => "✸",
+                Kind::Spark if particle.age < SPARK_LIFETIME * 2.0 / 3.0 => "*",
+                Kind::Spark => "·",
+            };
+
+            buf.get_mut(x, y)
+                .set_symbol(symbol)
+                .set_style(palette(particle.colour));
+        }
+    }
+}
@@ -1,15 +1,132 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
 use std::{
     error::Error,
     fmt,
     fs::File,
-    io::{BufRead, BufReader},
-    path::Path,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
 };
 
-#[derive(Debug, Clone, PartialEq)]
+/// Reasons a participant list or prize catalog can't be loaded.
+#[derive(Debug)]
+pub enum DataError {
+    /// The file doesn't exist.
+    NotFound(PathBuf),
+    /// The file exists but can't be opened.
+    PermissionDenied(PathBuf),
+    /// The file isn't valid UTF-8, starting at the given line.
+    InvalidEncoding { path: PathBuf, line: u64 },
+    /// A line couldn't be understood.
+    Invalid {
+        path: PathBuf,
+        line: u64,
+        reason: String,
+    },
+    /// The file has no participants in it.
+    Empty(PathBuf),
+    /// Any other problem reading the file.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl DataError {
+    fn io(path: &Path, source: io::Error) -> Self {
+        let path = path.to_path_buf();
+
+        match source.kind() {
+            io::ErrorKind::NotFound => DataError::NotFound(path),
+            io::ErrorKind::PermissionDenied => DataError::PermissionDenied(path),
+            _ => DataError::Io { path, source },
+        }
+    }
+
+    fn csv(path: &Path, source: csv::Error) -> Self {
+        let line = source.position().map_or(0, |p| p.line());
+        let reason = source.to_string();
+
+        match source.into_kind() {
+            csv::ErrorKind::Utf8 { .. } => DataError::InvalidEncoding {
+                path: path.to_path_buf(),
+                line,
+            },
+            csv::ErrorKind::Io(e) => DataError::io(path, e),
+            _ => DataError::Invalid {
+                path: path.to_path_buf(),
+                line,
+                reason,
+            },
+        }
+    }
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DataError::NotFound(path) => write!(f, "{} does not exist", path.display()),
+            DataError::PermissionDenied(path) => {
+                write!(f, "not allowed to read {}", path.display())
+            }
+            DataError::InvalidEncoding { path, line } => write!(
+                f,
+                "{} line {} is not valid UTF-8, save the file as UTF-8 and try again",
+                path.display(),
+                line
+            ),
+            DataError::Invalid { path, line, reason } => {
+                write!(f, "{} line {}: {}", path.display(), line, reason)
+            }
+            DataError::Empty(path) => write!(f, "{} has no participants", path.display()),
+            DataError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for DataError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            DataError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Participant {
     pub name: String,
     pub is_winner: bool,
+    /// Number of tickets in the draw, at least one.
+    pub tickets: u32,
+}
+
+impl Participant {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self::with_tickets(name, 1)
+    }
+
+    pub fn with_tickets(name: impl Into<String>, tickets: u32) -> Self {
+        Self {
+            name: name.into(),
+            is_winner: false,
+            tickets,
+        }
+    }
+
+    /// Parses a line like `Alice x3` into a participant with three tickets.
+    ///
+    /// Lines without a valid ticket suffix are taken as a name with a single ticket.
+    pub fn parse(line: &str) -> Self {
+        if let Some((name, suffix)) = line.trim_end().rsplit_once(' ') {
+            let tickets = suffix
+                .strip_prefix(['x', 'X', '×'])
+                .and_then(|n| n.parse::<u32>().ok());
+
+            if let Some(tickets @ 1..) = tickets {
+                return Self::with_tickets(name.trim_end(), tickets);
+            }
+        }
+
+        Self::new(line)
+    }
 }
 
 impl fmt::Display for Participant {
@@ -22,20 +139,274 @@ impl fmt::Display for Participant {
     }
 }
 
-pub fn read_participants_from_file(path: &Path) -> Result<Vec<Participant>, Box<dyn Error>> {
-    let file = File::open(path).expect("Could not read file: participants.txt");
+/// Something to give away, as listed in the prize catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prize {
+    pub title: String,
+    pub quantity: usize,
+    pub sponsor: Option<String>,
+}
+
+impl Prize {
+    /// Parses a catalog line like `Programming Rust | 2 | O'Reilly`.
+    ///
+    /// The quantity defaults to one and the sponsor is optional.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut fields = line.split('|').map(str::trim);
 
-    let lines = BufReader::new(file).lines();
+        let title = fields.next().unwrap_or_default().to_string();
+        let quantity = match fields.next() {
+            None | Some("") => 1,
+            Some(quantity) => quantity
+                .parse()
+                .map_err(|_| format!("invalid quantity \"{}\" for {}", quantity, title))?,
+        };
+        let sponsor = fields
+            .next()
+            .filter(|sponsor| !sponsor.is_empty())
+            .map(str::to_string);
 
-    let participants: Vec<Participant> = lines
-        .into_iter()
-        .map(|p| -> Participant {
-            Participant {
-                name: p.unwrap(),
-                is_winner: false,
-            }
+        Ok(Self {
+            title,
+            quantity,
+            sponsor,
         })
+    }
+}
+
+impl fmt::Display for Prize {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.sponsor {
+            Some(sponsor) => write!(f, "{} ({})", self.title, sponsor),
+            None => write!(f, "{}", self.title),
+        }
+    }
+}
+
+/// A participant who won a draw, and what they won.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Winner {
+    pub participant: Participant,
+    pub prize: Option<Prize>,
+    /// Line of the prize in the catalog, which tells apart prizes listed more than once.
+    #[serde(default)]
+    pub prize_index: Option<usize>,
+    pub drawn_at: DateTime<Local>,
+    /// Everyone knocked out before the winner was left standing, in order. Empty for spins.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eliminated: Vec<String>,
+}
+
+impl fmt::Display for Winner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.prize {
+            Some(prize) => write!(f, "{} – {}", self.participant, prize.title),
+            None => write!(f, "{}", self.participant),
+        }
+    }
+}
+
+/// Reads one participant per line. Surrounding whitespace, blank lines and `#` comments are
+/// dropped.
+pub fn read_participants_from_file(path: &Path) -> Result<Vec<Participant>, DataError> {
+    let participants: Vec<Participant> = read_lines(path)?
+        .iter()
+        .map(|(_, line)| Participant::parse(line))
         .collect();
 
+    if participants.is_empty() {
+        return Err(DataError::Empty(path.to_path_buf()));
+    }
+
     Ok(participants)
 }
+
+/// Reads the prize catalog, one prize per line. Blank lines and `#` comments are ignored.
+pub fn read_prizes_from_file(path: &Path) -> Result<Vec<Prize>, DataError> {
+    read_lines(path)?
+        .iter()
+        .map(|(number, line)| {
+            Prize::parse(line).map_err(|reason| DataError::Invalid {
+                path: path.to_path_buf(),
+                line: *number,
+                reason,
+            })
+        })
+        .collect()
+}
+
+/// Trimmed lines of the file with their line numbers, leaving out blank lines and comments.
+fn read_lines(path: &Path) -> Result<Vec<(u64, String)>, DataError> {
+    let file = File::open(path).map_err(|e| DataError::io(path, e))?;
+
+    let mut lines = Vec::new();
+    for (number, line) in (1..).zip(BufReader::new(file).lines()) {
+        let line = line.map_err(|e| match e.kind() {
+            io::ErrorKind::InvalidData => DataError::InvalidEncoding {
+                path: path.to_path_buf(),
+                line: number,
+            },
+            _ => DataError::io(path, e),
+        })?;
+
+        let line = line.trim();
+        if !line.is_empty() && !line.starts_with('#') {
+            lines.push((number, line.to_string()));
+        }
+    }
+
+    Ok(lines)
+}
+
+/// Supported participant list formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Format {
+    /// One name per line.
+    Text,
+    /// RSVP export from Meetup.
+    MeetupCsv,
+}
+
+impl Format {
+    /// Guesses the format from the file extension, falling back to plain text.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => Format::MeetupCsv,
+            _ => Format::Text,
+        }
+    }
+}
+
+/// A row that was left out of the import, and why.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedRow {
+    pub line: u64,
+    pub reason: String,
+}
+
+impl fmt::Display for SkippedRow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+/// Result of loading a participant list.
+#[derive(Debug, Default)]
+pub struct Import {
+    pub participants: Vec<Participant>,
+    pub skipped: Vec<SkippedRow>,
+}
+
+/// Loads participants in the given format, or the one implied by the file extension.
+pub fn import_participants(
+    path: &Path,
+    format: Option<Format>,
+    include_guests: bool,
+) -> Result<Import, DataError> {
+    match format.unwrap_or_else(|| Format::from_path(path)) {
+        Format::Text => Ok(Import {
+            participants: read_participants_from_file(path)?,
+            skipped: Vec::new(),
+        }),
+        Format::MeetupCsv => match read_participants_from_meetup_csv(path, include_guests)? {
+            import if import.participants.is_empty() => Err(DataError::Empty(path.to_path_buf())),
+            import => Ok(import),
+        },
+    }
+}
+
+/// Reads the attendees from a Meetup RSVP export.
+///
+/// Only "Yes" RSVPs are kept. With `include_guests`, each guest gets an entry of their own
+/// named after the member who brought them. An optional "Tickets" column sets the weight.
+pub fn read_participants_from_meetup_csv(
+    path: &Path,
+    include_guests: bool,
+) -> Result<Import, DataError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_path(path)
+        .map_err(|e| DataError::csv(path, e))?;
+
+    let headers = reader
+        .headers()
+        .map_err(|e| DataError::csv(path, e))?
+        .clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|h| h.trim_start_matches('\u{feff}').eq_ignore_ascii_case(name))
+    };
+
+    let name_column = column("Name").ok_or_else(|| DataError::Invalid {
+        path: path.to_path_buf(),
+        line: 1,
+        reason: "there is no \"Name\" column".to_string(),
+    })?;
+    let rsvp_column = column("RSVP");
+    let guests_column = column("Guests");
+    let tickets_column = column("Tickets");
+
+    let mut import = Import::default();
+
+    for record in reader.records() {
+        let record = record.map_err(|e| DataError::csv(path, e))?;
+        let line = record.position().map_or(0, |p| p.line());
+
+        let mut skip = |reason: String| import.skipped.push(SkippedRow { line, reason });
+
+        let name = record.get(name_column).unwrap_or_default();
+        if name.is_empty() {
+            skip("no name".to_string());
+            continue;
+        }
+
+        if let Some(rsvp) = rsvp_column.map(|c| record.get(c).unwrap_or_default()) {
+            if !rsvp.eq_ignore_ascii_case("yes") {
+                skip(format!("{} answered \"{}\"", name, rsvp));
+                continue;
+            }
+        }
+
+        let guests = match guests_column.map(|c| record.get(c).unwrap_or_default()) {
+            None | Some("") => 0,
+            Some(guests) => match guests.parse::<usize>() {
+                Ok(guests) => guests,
+                Err(_) => {
+                    skip(format!(
+                        "guests of {} left out, invalid count \"{}\"",
+                        name, guests
+                    ));
+                    0
+                }
+            },
+        };
+
+        let tickets = match tickets_column.map(|c| record.get(c).unwrap_or_default()) {
+            None | Some("") => 1,
+            Some(tickets) => match tickets.parse::<u32>() {
+                Ok(tickets @ 1..) => tickets,
+                _ => {
+                    skip(format!(
+                        "{} kept with 1 ticket, invalid count \"{}\"",
+                        name, tickets
+                    ));
+                    1
+                }
+            },
+        };
+
+        import
+            .participants
+            .push(Participant::with_tickets(name, tickets));
+
+        if include_guests {
+            import
+                .participants
+                .extend((1..=guests).map(|guest| Participant::new(format!("{} +{}", name, guest))));
+        }
+    }
+
+    Ok(import)
+}
@@ -0,0 +1,160 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+use crate::data::Participant;
+
+/// How alike two names are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateKind {
+    /// Exactly the same name.
+    Exact,
+    /// The same name apart from case or accents, like "Søren" and "soren".
+    CaseOrAccent,
+    /// Probably a typo, like "Jon Smith" and "John Smith".
+    Similar,
+}
+
+impl std::fmt::Display for DuplicateKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            DuplicateKind::Exact => write!(f, "same name"),
+            DuplicateKind::CaseOrAccent => write!(f, "same apart from case or accents"),
+            DuplicateKind::Similar => write!(f, "similar"),
+        }
+    }
+}
+
+/// A participant that looks like an earlier one in the list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duplicate {
+    pub kind: DuplicateKind,
+    /// Index of the participant that is kept.
+    pub original: usize,
+    /// Index of the participant that would be merged into the original.
+    pub duplicate: usize,
+    /// Should the duplicate be merged? Only certain duplicates are merged by default.
+    pub merge: bool,
+}
+
+/// Finds participants that look like someone earlier in the list.
+///
+/// Each participant is compared to the ones before it and paired with the closest match.
+pub fn find_duplicates(participants: &[Participant]) -> Vec<Duplicate> {
+    let folded: Vec<String> = participants.iter().map(|p| fold(&p.name)).collect();
+
+    let mut duplicates = Vec::new();
+
+    for duplicate in 0..participants.len() {
+        let closest = (0..duplicate)
+            .filter_map(|original| {
+                let kind = compare(
+                    &participants[original].name,
+                    &participants[duplicate].name,
+                    &folded[original],
+                    &folded[duplicate],
+                )?;
+                Some((kind, original))
+            })
+            .min_by_key(|(kind, _)| *kind as u8);
+
+        if let Some((kind, original)) = closest {
+            duplicates.push(Duplicate {
+                kind,
+                original,
+                duplicate,
+                merge: kind != DuplicateKind::Similar,
+            });
+        }
+    }
+
+    duplicates
+}
+
+/// Merges each of `duplicates` into its original, keeping the highest ticket count.
+pub fn merge(participants: &mut Vec<Participant>, duplicates: &[&Duplicate]) {
+    let mut duplicates = duplicates.to_vec();
+
+    // Remove from the back so the remaining indices stay valid.
+    duplicates.sort_by_key(|d| std::cmp::Reverse(d.duplicate));
+    for duplicate in duplicates {
+        let removed = participants.remove(duplicate.duplicate);
+        let original = &mut participants[duplicate.original];
+        original.tickets = original.tickets.max(removed.tickets);
+    }
+}
+
+/// Merges the duplicates that the review ticks by default, as if it was confirmed straight
+/// away. Returns how many were merged.
+pub fn merge_suggested(participants: &mut Vec<Participant>) -> usize {
+    let duplicates = find_duplicates(participants);
+    let merges: Vec<&Duplicate> = duplicates.iter().filter(|d| d.merge).collect();
+
+    merge(participants, &merges);
+    merges.len()
+}
+
+fn compare(a: &str, b: &str, folded_a: &str, folded_b: &str) -> Option<DuplicateKind> {
+    if a == b {
+        return Some(DuplicateKind::Exact);
+    }
+
+    if folded_a == folded_b {
+        return Some(DuplicateKind::CaseOrAccent);
+    }
+
+    // Short names are too often one letter apart by coincidence.
+    let length = folded_a.chars().count().min(folded_b.chars().count());
+    let allowed = match length {
+        0..=3 => 0,
+        4..=9 => 1,
+        _ => 2,
+    };
+
+    match allowed > 0 && levenshtein(folded_a, folded_b) <= allowed {
+        true => Some(DuplicateKind::Similar),
+        false => None,
+    }
+}
+
+/// Lowercases `name`, strips accents and collapses whitespace, so "  Søren  Ærø" becomes
+/// "soren aero".
+pub fn fold(name: &str) -> String {
+    let stripped: String = name
+        .nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+        .map(|c| match c {
+            // Letters that don't decompose into a base letter and a mark.
+            'ø' => "o".to_string(),
+            'æ' => "ae".to_string(),
+            'œ' => "oe".to_string(),
+            'ß' => "ss".to_string(),
+            'ł' => "l".to_string(),
+            'đ' => "d".to_string(),
+            c => c.to_string(),
+        })
+        .collect();
+
+    stripped.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Number of single character edits between `a` and `b`.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = match ca == *cb {
+                true => diagonal,
+                false => 1 + diagonal.min(above).min(row[j]),
+            };
+            diagonal = above;
+        }
+    }
+
+    row[b.len()]
+}
@@ -0,0 +1,111 @@
+use rand::Rng;
+use std::time::Duration;
+
+/// Each round is this much shorter than the one before, so the pace picks up towards the end.
+const SPEED_UP: f64 = 0.85;
+
+/// Share of the participants still in the running that is knocked out in one round.
+const BATCH_DIVISOR: usize = 4;
+
+/// A "last one standing" draw: everyone but the winner is knocked out, in shrinking batches
+/// and at a quickening pace.
+///
+/// The winner is picked up front exactly like a spin picks one, so the odds are the same.
+#[derive(Debug, Clone)]
+pub struct Elimination {
+    /// Participants in the order they are knocked out, by index into the participant list.
+    pub order: Vec<usize>,
+    /// The last one standing.
+    pub winner: usize,
+    /// How many of [`order`](Elimination::order) are out so far.
+    pub knocked_out: usize,
+    /// When each round ends, counted from the start, and how many are out after it.
+    rounds: Vec<(Duration, usize)>,
+    duration: Duration,
+}
+
+impl Elimination {
+    /// Plans an elimination of `others` that leaves `winner`, spread over `duration`.
+    ///
+    /// `others` are `(index, tickets)` pairs. Whoever holds more tickets tends to stay in
+    /// longer, but the order has no say in who wins.
+    pub fn new(
+        winner: usize,
+        mut others: Vec<(usize, u32)>,
+        duration: Duration,
+        rng: &mut impl Rng,
+    ) -> Self {
+        // Draw the others one by one, weighted by tickets; the first drawn is knocked out last.
+        let mut order = Vec::with_capacity(others.len());
+        while !others.is_empty() {
+            let total: u64 = others.iter().map(|&(_, t)| u64::from(t.max(1))).sum();
+            let mut ticket = rng.gen_range(0..total);
+
+            let mut position = 0;
+            while ticket >= u64::from(others[position].1.max(1)) {
+                ticket -= u64::from(others[position].1.max(1));
+                position += 1;
+            }
+            order.push(others.remove(position).0);
+        }
+        order.reverse();
+
+        Self {
+            rounds: schedule(order.len(), duration),
+            order,
+            winner,
+            knocked_out: 0,
+            duration,
+        }
+    }
+
+    pub fn is_knocked_out(&self, index: usize) -> bool {
+        self.order[..self.knocked_out].contains(&index)
+    }
+
+    /// Participants still in the running, the winner included.
+    pub fn still_standing(&self) -> usize {
+        self.order.len() - self.knocked_out + 1
+    }
+
+    /// Knocks out everyone due by `elapsed` after the start. Returns whether the draw is over.
+    pub fn advance(&mut self, elapsed: Duration) -> bool {
+        for &(ends, out) in &self.rounds {
+            if ends <= elapsed {
+                self.knocked_out = self.knocked_out.max(out);
+            }
+        }
+
+        elapsed >= self.duration
+    }
+}
+
+/// End times of the rounds that knock out `count` participants over `duration`.
+///
+/// Each round takes out a quarter of those left, but at least one, and is shorter than the
+/// round before. The last round ends at `duration`.
+fn schedule(count: usize, duration: Duration) -> Vec<(Duration, usize)> {
+    let mut batches = Vec::new();
+    let mut left = count;
+    while left > 0 {
+        let batch = ((left + 1) / BATCH_DIVISOR).max(1);
+        batches.push(batch);
+        left -= batch;
+    }
+
+    // The round lengths form a geometric series that adds up to `duration`.
+    let first =
+        duration.as_secs_f64() * (1.0 - SPEED_UP) / (1.0 - SPEED_UP.powi(batches.len() as i32));
+
+    let mut ends = 0.0;
+    let mut out = 0;
+    batches
+        .iter()
+        .enumerate()
+        .map(|(round, batch)| {
+            ends += first * SPEED_UP.powi(round as i32);
+            out += batch;
+            (Duration::from_secs_f64(ends).min(duration), out)
+        })
+        .collect()
+}
@@ -1,23 +1,49 @@
 use crate::app::AppResult;
 use crossterm::event::{self, Event as CrosstermEvent, KeyEvent, MouseEvent};
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::thread;
 use std::time::{Duration, Instant};
 
 /// Terminal events.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub enum Event {
     /// Terminal tick.
     Tick,
+    /// Time to draw a frame.
+    Render,
     /// Key press.
     Key(KeyEvent),
     /// Mouse click/scroll.
     Mouse(MouseEvent),
     /// Terminal resize.
     Resize(u16, u16),
+    /// The terminal window got focus.
+    FocusGained,
+    /// The terminal window lost focus.
+    FocusLost,
+    /// Text pasted into the terminal.
+    Paste(String),
+}
+
+impl From<CrosstermEvent> for Event {
+    fn from(event: CrosstermEvent) -> Self {
+        match event {
+            CrosstermEvent::Key(e) => Event::Key(e),
+            CrosstermEvent::Mouse(e) => Event::Mouse(e),
+            CrosstermEvent::Resize(w, h) => Event::Resize(w, h),
+            CrosstermEvent::FocusGained => Event::FocusGained,
+            CrosstermEvent::FocusLost => Event::FocusLost,
+            CrosstermEvent::Paste(text) => Event::Paste(text),
+        }
+    }
 }
 
 /// Terminal event handler.
+///
+/// Logic ticks arrive at a fixed rate. Frames are only sent when asked for: one at a time
+/// with [`request_frame`](EventHandler::request_frame), or continuously at the frame rate
+/// while [`animating`](EventHandler::set_animating).
 #[allow(dead_code)]
 #[derive(Debug)]
 pub struct EventHandler {
@@ -27,13 +53,25 @@ pub struct EventHandler {
     receiver: mpsc::Receiver<Event>,
     /// Event handler thread.
     handler: thread::JoinHandle<()>,
+    /// Frame timer thread.
+    frames: thread::JoinHandle<()>,
+    /// Should frames be sent at the frame rate?
+    animating: Arc<AtomicBool>,
+    /// Is a frame waiting in the channel? Keeps frames from piling up.
+    frame_pending: Arc<AtomicBool>,
 }
 
 impl EventHandler {
     /// Constructs a new instance of [`EventHandler`].
-    pub fn new(tick_rate: u64) -> Self {
+    ///
+    /// Both rates are intervals in milliseconds.
+    pub fn new(tick_rate: u64, frame_rate: u64) -> Self {
         let tick_rate = Duration::from_millis(tick_rate);
+        let frame_rate = Duration::from_millis(frame_rate);
         let (sender, receiver) = mpsc::channel();
+        let animating = Arc::new(AtomicBool::new(false));
+        let frame_pending = Arc::new(AtomicBool::new(false));
+
         let handler = {
             let sender = sender.clone();
             thread::spawn(move || {
@@ -43,14 +81,19 @@ impl EventHandler {
                         .checked_sub(last_tick.elapsed())
                         .unwrap_or(tick_rate);
 
-                    if event::poll(timeout).expect("no events available") {
-                        match event::read().expect("unable to read event") {
-                            CrosstermEvent::Key(e) => sender.send(Event::Key(e)),
-                            CrosstermEvent::Mouse(e) => sender.send(Event::Mouse(e)),
-                            CrosstermEvent::Resize(w, h) => sender.send(Event::Resize(w, h)),
-                            _ => unimplemented!(),
+                    // Without a readable terminal there is no input, but ticks keep coming.
+                    let has_event = event::poll(timeout).unwrap_or_else(|_| {
+                        thread::sleep(timeout);
+                        false
+                    });
+
+                    // Input the terminal sends that crossterm can't make sense of is dropped.
+                    if has_event {
+                        if let Ok(event) = event::read() {
+                            sender
+                                .send(event.into())
+                                .expect("failed to send terminal event");
                         }
-                        .expect("failed to send terminal event")
                     }
 
                     if last_tick.elapsed() >= tick_rate {
@@ -60,10 +103,34 @@ impl EventHandler {
                 }
             })
         };
+
+        let frames = {
+            let sender = sender.clone();
+            let animating = animating.clone();
+            let frame_pending = frame_pending.clone();
+            thread::spawn(move || loop {
+                // Sleep until animation is switched on, see `set_animating`.
+                while !animating.load(Ordering::Acquire) {
+                    thread::park();
+                }
+
+                if !frame_pending.swap(true, Ordering::AcqRel)
+                    && sender.send(Event::Render).is_err()
+                {
+                    return;
+                }
+
+                thread::sleep(frame_rate);
+            })
+        };
+
         Self {
             sender,
             receiver,
             handler,
+            frames,
+            animating,
+            frame_pending,
         }
     }
 
@@ -72,6 +139,29 @@ impl EventHandler {
     /// This function will always block the current thread if
     /// there is no data available and it's possible for more data to be sent.
     pub fn next(&self) -> AppResult<Event> {
-        Ok(self.receiver.recv()?)
+        let event = self.receiver.recv()?;
+
+        if let Event::Render = event {
+            self.frame_pending.store(false, Ordering::Release);
+        }
+
+        Ok(event)
+    }
+
+    /// Asks for a single frame, for example after a key press changed something.
+    pub fn request_frame(&self) {
+        if !self.frame_pending.swap(true, Ordering::AcqRel) {
+            // The receiver lives as long as `self`, so this can't fail.
+            let _ = self.sender.send(Event::Render);
+        }
+    }
+
+    /// Switches continuous frames at the frame rate on or off.
+    pub fn set_animating(&self, animating: bool) {
+        let was_animating = self.animating.swap(animating, Ordering::AcqRel);
+
+        if animating && !was_animating {
+            self.frames.thread().unpark();
+        }
     }
 }
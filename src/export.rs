@@ -0,0 +1,176 @@
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use std::{error::Error, fs, path::Path};
+
+use crate::app::{App, AppResult};
+
+/// Supported result formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl ExportFormat {
+    /// Picks the format from the file extension, falling back to Markdown.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ExportFormat::Json,
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            _ => ExportFormat::Markdown,
+        }
+    }
+}
+
+/// Summary of a session, as written to disk.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub exported_at: DateTime<Local>,
+    pub participant_count: usize,
+    pub draw_mode: String,
+    /// Left out until the session is over.
+    pub seed: Option<String>,
+    pub commitment: String,
+    pub winners: Vec<ReportWinner>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReportWinner {
+    pub round: usize,
+    pub name: String,
+    pub prize: Option<String>,
+    pub sponsor: Option<String>,
+    pub drawn_at: DateTime<Local>,
+    /// Knocked out before the winner was the last one standing, first out first.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub eliminated: Vec<String>,
+}
+
+impl Report {
+    pub fn new(app: &App) -> Self {
+        let winners = app
+            .all_winners
+            .iter()
+            .enumerate()
+            .map(|(i, winner)| ReportWinner {
+                round: i + 1,
+                name: winner.participant.name.clone(),
+                prize: winner.prize.as_ref().map(|p| p.title.clone()),
+                sponsor: winner.prize.as_ref().and_then(|p| p.sponsor.clone()),
+                drawn_at: winner.drawn_at,
+                eliminated: winner.eliminated.clone(),
+            })
+            .collect();
+
+        Self {
+            exported_at: Local::now(),
+            participant_count: app.all_participants.items.len(),
+            draw_mode: app.draw_mode.to_string(),
+            seed: Some(app.seed.to_hex()).filter(|_| app.seed_revealed),
+            commitment: app.commitment(),
+            winners,
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, Box<dyn Error>> {
+        Ok(serde_json::to_string_pretty(self)? + "\n")
+    }
+
+    /// One row per winner. Session details are repeated on each row so the file stands alone.
+    pub fn to_csv(&self) -> Result<String, Box<dyn Error>> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record([
+            "round",
+            "name",
+            "prize",
+            "sponsor",
+            "drawn_at",
+            "participant_count",
+            "seed",
+            "eliminated",
+        ])?;
+
+        for winner in &self.winners {
+            writer.write_record([
+                winner.round.to_string(),
+                winner.name.clone(),
+                winner.prize.clone().unwrap_or_default(),
+                winner.sponsor.clone().unwrap_or_default(),
+                winner.drawn_at.to_rfc3339(),
+                self.participant_count.to_string(),
+                self.seed.clone().unwrap_or_default(),
+                winner.eliminated.join("; "),
+            ])?;
+        }
+
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+
+    /// A table that can be pasted into a meetup recap post.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "## Raffle results\n\n{} winners drawn {} among {} participants on {}.\n\n",
+            self.winners.len(),
+            self.draw_mode,
+            self.participant_count,
+            self.exported_at.format("%Y-%m-%d"),
+        );
+
+        markdown.push_str("| # | Winner | Prize | Time |\n|---|--------|-------|------|\n");
+        for winner in &self.winners {
+            let prize = match (&winner.prize, &winner.sponsor) {
+                (Some(prize), Some(sponsor)) => format!("{} (thanks to {})", prize, sponsor),
+                (Some(prize), None) => prize.clone(),
+                _ => String::new(),
+            };
+
+            markdown.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                winner.round,
+                escape_markdown(&winner.name),
+                escape_markdown(&prize),
+                winner.drawn_at.format("%H:%M"),
+            ));
+        }
+
+        for winner in self.winners.iter().filter(|w| !w.eliminated.is_empty()) {
+            markdown.push_str(&format!(
+                "\nRound {} was last one standing. Knocked out, in order: {}.\n",
+                winner.round,
+                winner.eliminated.join(", "),
+            ));
+        }
+
+        markdown.push_str(&match &self.seed {
+            Some(seed) => format!(
+                "\nSeed `{}` (commitment `{}`). Check the draw with `raffle verify --seed {}`.\n",
+                seed, self.commitment, seed,
+            ),
+            None => format!(
+                "\nCommitment `{}`. The seed is revealed when the session ends.\n",
+                self.commitment,
+            ),
+        });
+
+        markdown
+    }
+}
+
+/// Writes the results of the session to `path`.
+pub fn write(app: &App, path: &Path, format: ExportFormat) -> AppResult<()> {
+    let report = Report::new(app);
+
+    let contents = match format {
+        ExportFormat::Json => report.to_json()?,
+        ExportFormat::Csv => report.to_csv()?,
+        ExportFormat::Markdown => report.to_markdown(),
+    };
+
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+fn escape_markdown(text: &str) -> String {
+    text.replace('|', "\\|")
+}
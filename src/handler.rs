@@ -1,11 +1,31 @@
-use crate::app::{App, AppResult};
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crate::app::{App, AppResult, InputMode};
+use crate::ui::Target;
+use crossterm::event::{
+    KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
 
 /// Handles the key events and updates the state of [`App`].
 pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
     if key_event.kind != KeyEventKind::Press {
         return Ok(());
     }
+
+    match app.input_mode {
+        InputMode::Normal => {}
+        InputMode::Search => {
+            handle_search_key_events(key_event, app);
+            return Ok(());
+        }
+        InputMode::Review => {
+            handle_review_key_events(key_event, app);
+            return Ok(());
+        }
+        _ => {
+            handle_input_key_events(key_event, app);
+            return Ok(());
+        }
+    }
+
     match key_event.code {
         // Exit application on `q`
         KeyCode::Char('q') => {
@@ -13,21 +33,49 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
         }
 
         // Exit application on `Ctrl-C`
-        KeyCode::Char('c') | KeyCode::Char('C') => {
-            if key_event.modifiers == KeyModifiers::CONTROL {
-                app.quit();
-            }
+        KeyCode::Char('c') | KeyCode::Char('C') if key_event.modifiers == KeyModifiers::CONTROL => {
+            app.quit();
         }
 
         // Tabs
-        KeyCode::Tab => app.tabs.next_tab(),
+        KeyCode::Tab => app.next_tab(),
 
         // Spin actions
         KeyCode::Char('s') => app.start_spin(),
 
+        KeyCode::Char('r') if key_event.modifiers == KeyModifiers::CONTROL => app.redo(),
+
         KeyCode::Char('r') => app.reset_spin(),
 
-        // List handlers
+        // Participant editor
+        KeyCode::Char('a') => app.start_input(InputMode::Add),
+
+        KeyCode::Char('n') => app.start_input(InputMode::Rename),
+
+        KeyCode::Char('p') => app.start_input(InputMode::Paste),
+
+        // Search
+        KeyCode::Char('/') => app.start_search(),
+
+        // History
+        KeyCode::Char('u') => app.undo(),
+
+        KeyCode::Char('m') => app.toggle_draw_mode(),
+
+        KeyCode::Char('l') => app.toggle_draw_style(),
+
+        KeyCode::Char('f') => app.toggle_presentation(),
+
+        KeyCode::Char('v') => app.next_visual(),
+
+        KeyCode::Char('t') => app.next_theme(),
+
+        // Export
+        KeyCode::Char('e') => app.export_results(),
+
+        // List handlers, left alone during a spin as it lands relative to the selection.
+        KeyCode::Up | KeyCode::Down | KeyCode::Esc if app.is_spinning => {}
+
         KeyCode::Up => {
             app.all_participants.previous();
         }
@@ -41,10 +89,164 @@ pub fn handle_key_events(key_event: KeyEvent, app: &mut App) -> AppResult<()> {
         }
 
         KeyCode::Backspace => {
-            app.all_participants.remove();
+            app.remove_selected();
         }
 
         _ => {}
     }
     Ok(())
 }
+
+/// Handles clicks and scrolling on the targets drawn in the last frame.
+pub fn handle_mouse_events(mouse_event: MouseEvent, app: &mut App) {
+    // Editors and the review screen are modal, so they only listen to the keyboard.
+    if !matches!(app.input_mode, InputMode::Normal | InputMode::Search) {
+        return;
+    }
+
+    let Some(target) = app.hit_areas.hit(mouse_event.column, mouse_event.row) else {
+        return;
+    };
+
+    match (mouse_event.kind, target) {
+        (MouseEventKind::Down(MouseButton::Left), Target::Tab(index)) => app.select_tab(index),
+        (MouseEventKind::Down(MouseButton::Left), Target::Participant(index)) => {
+            app.select_participant(index)
+        }
+        (MouseEventKind::Down(MouseButton::Left), Target::SpinButton)
+            if app.input_mode == InputMode::Normal =>
+        {
+            app.start_spin()
+        }
+        (MouseEventKind::Down(MouseButton::Left), Target::ResetButton)
+            if app.input_mode == InputMode::Normal =>
+        {
+            app.reset_spin()
+        }
+
+        (MouseEventKind::ScrollDown, Target::List | Target::Participant(_)) if !app.is_spinning => {
+            match app.input_mode {
+                InputMode::Search => app.select_match(true),
+                _ => app.all_participants.next(),
+            }
+        }
+        (MouseEventKind::ScrollUp, Target::List | Target::Participant(_)) if !app.is_spinning => {
+            match app.input_mode {
+                InputMode::Search => app.select_match(false),
+                _ => app.all_participants.previous(),
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// Handles text pasted into the terminal.
+///
+/// Open text fields get the text as if it was typed; otherwise each line becomes a participant.
+pub fn handle_paste_event(text: &str, app: &mut App) {
+    // Some terminals send bare carriage returns between pasted lines.
+    let text = &text.replace("\r\n", "\n").replace('\r', "\n");
+
+    match app.input_mode {
+        InputMode::Normal => app.paste_participants(text),
+        InputMode::Review => {}
+        InputMode::Paste => app.input.insert_str(text),
+        InputMode::Add if text.lines().nth(1).is_some() => {
+            // Several names don't fit on one line, so carry on in the bulk editor.
+            app.input_mode = InputMode::Paste;
+            app.input.insert_str(text);
+        }
+        InputMode::Add | InputMode::Rename => app.input.insert_str(&single_line(text)),
+        InputMode::Search => {
+            app.input.insert_str(&single_line(text));
+            app.update_search();
+        }
+    }
+}
+
+/// Joins pasted lines with spaces, for text fields that only hold one line.
+fn single_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Handles the key events while the participant editor is open.
+fn handle_input_key_events(key_event: KeyEvent, app: &mut App) {
+    match key_event.code {
+        KeyCode::Esc => app.cancel_input(),
+
+        // Several names can be entered when pasting, so submitting needs its own key.
+        KeyCode::Enter if app.input_mode == InputMode::Paste => app.input.insert('\n'),
+
+        KeyCode::Char('s')
+            if app.input_mode == InputMode::Paste
+                && key_event.modifiers == KeyModifiers::CONTROL =>
+        {
+            app.submit_input()
+        }
+
+        KeyCode::Enter => app.submit_input(),
+
+        KeyCode::Char(c) => app.input.insert(c),
+        KeyCode::Backspace => app.input.backspace(),
+        KeyCode::Delete => app.input.delete(),
+        KeyCode::Left => app.input.move_left(),
+        KeyCode::Right => app.input.move_right(),
+        KeyCode::Home => app.input.move_home(),
+        KeyCode::End => app.input.move_end(),
+
+        _ => {}
+    }
+}
+
+/// Handles the key events while searching the participant list.
+fn handle_search_key_events(key_event: KeyEvent, app: &mut App) {
+    let control = key_event.modifiers == KeyModifiers::CONTROL;
+
+    match key_event.code {
+        KeyCode::Esc => app.cancel_search(),
+        KeyCode::Enter => app.jump_to_match(),
+
+        KeyCode::Down => app.select_match(true),
+        KeyCode::Up => app.select_match(false),
+
+        KeyCode::Char('d') if control => app.remove_match(),
+        KeyCode::Char('t') if control => app.toggle_winner_mark(),
+
+        KeyCode::Char(c) => {
+            app.input.insert(c);
+            app.update_search();
+        }
+        KeyCode::Backspace => {
+            app.input.backspace();
+            app.update_search();
+        }
+        KeyCode::Delete => {
+            app.input.delete();
+            app.update_search();
+        }
+        KeyCode::Left => app.input.move_left(),
+        KeyCode::Right => app.input.move_right(),
+        KeyCode::Home => app.input.move_home(),
+        KeyCode::End => app.input.move_end(),
+
+        _ => {}
+    }
+}
+
+/// Handles the key events on the duplicate review screen.
+fn handle_review_key_events(key_event: KeyEvent, app: &mut App) {
+    let Some(review) = &mut app.review else {
+        return;
+    };
+
+    match key_event.code {
+        KeyCode::Up => review.previous(),
+        KeyCode::Down => review.next(),
+        KeyCode::Char(' ') => app.toggle_merge(),
+        KeyCode::Enter => app.confirm_review(),
+        KeyCode::Esc => app.skip_review(),
+        KeyCode::Char('q') => app.quit(),
+        _ => {}
+    }
+}
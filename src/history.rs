@@ -0,0 +1,44 @@
+use crate::session::Session;
+
+/// How many steps can be undone.
+const MAX_HISTORY: usize = 100;
+
+/// Undo and redo stacks of [`Session`] snapshots, each labelled with the action it reverts.
+#[derive(Debug, Default)]
+pub struct History {
+    undo: Vec<(String, Session)>,
+    redo: Vec<(String, Session)>,
+}
+
+impl History {
+    /// Remembers the state from before `action`. Anything that could be redone is dropped.
+    pub fn record(&mut self, action: String, before: Session) {
+        if self.undo.len() == MAX_HISTORY {
+            self.undo.remove(0);
+        }
+
+        self.undo.push((action, before));
+        self.redo.clear();
+    }
+
+    /// Renames the most recent action, once it's known what it did.
+    pub fn relabel_last(&mut self, action: String) {
+        if let Some((last, _)) = self.undo.last_mut() {
+            *last = action;
+        }
+    }
+
+    /// Swaps `current` for the state before the last action.
+    pub fn undo(&mut self, current: Session) -> Option<(String, Session)> {
+        let (action, before) = self.undo.pop()?;
+        self.redo.push((action.clone(), current));
+        Some((action, before))
+    }
+
+    /// Swaps `current` for the state after the last undone action.
+    pub fn redo(&mut self, current: Session) -> Option<(String, Session)> {
+        let (action, after) = self.redo.pop()?;
+        self.undo.push((action.clone(), current));
+        Some((action, after))
+    }
+}
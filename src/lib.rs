@@ -18,3 +18,48 @@ pub mod data;
 
 /// Reusable styles.
 pub mod styles;
+
+/// Colour themes.
+pub mod theme;
+
+/// Event branding for the Home tab.
+pub mod branding;
+
+/// Verifiable draw seeds.
+pub mod seed;
+
+/// Results export.
+pub mod export;
+
+/// Session persistence.
+pub mod session;
+
+/// Undo/redo history.
+pub mod history;
+
+/// Text input state.
+pub mod input;
+
+/// Fuzzy participant search.
+pub mod search;
+
+/// Duplicate detection.
+pub mod dedup;
+
+/// Spin animation timing.
+pub mod spin;
+
+/// Big block-letter text.
+pub mod bigtext;
+
+/// Celebration particle effects.
+pub mod celebration;
+
+/// Wheel-of-fortune visual.
+pub mod wheel;
+
+/// Slot-machine reel visual.
+pub mod reel;
+
+/// Last-one-standing draws.
+pub mod elimination;
@@ -1,52 +1,401 @@
-use clap::Parser;
-use raffle::app::{App, AppResult};
+use clap::builder::RangedU64ValueParser;
+use clap::{Parser, Subcommand};
+use crossterm::event::MouseEventKind;
+use raffle::app::{App, AppResult, DrawMode, DrawStyle};
+use raffle::branding::Branding;
+use raffle::data::{self, Format, Participant};
+use raffle::dedup;
 use raffle::event::{Event, EventHandler};
-use raffle::handler::handle_key_events;
+use raffle::export::{self, ExportFormat};
+use raffle::handler::{handle_key_events, handle_mouse_events, handle_paste_event};
+use raffle::seed::Seed;
+use raffle::session::Session;
+use raffle::spin::{Easing, SpinConfig, Visual, MAX_ROTATIONS};
+use raffle::theme::{self, Theme, Themes};
 
 use raffle::tui::Tui;
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use std::io;
-use std::path::{PathBuf};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
 
+/// Interval between logic ticks, in milliseconds.
 const TICK_RATE: u64 = 100;
 
+/// Interval between frames while animating, in milliseconds.
+const FRAME_RATE: u64 = 16;
+
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 struct Args {
     #[arg(short, long)]
     participants_file: Option<PathBuf>,
+
+    /// Format of the participants file (guessed from the extension if omitted)
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
+
+    /// Add an entry for each guest in a Meetup export
+    #[arg(long)]
+    include_guests: bool,
+
+    /// Prize catalog (defaults to prizes.txt next to the participants file, if present)
+    #[arg(long)]
+    prizes_file: Option<PathBuf>,
+
+    /// Write the results here on exit (.json, .csv or .md)
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// Format of the results file (guessed from the extension if omitted)
+    #[arg(long, value_enum)]
+    output_format: Option<ExportFormat>,
+
+    /// How the spin slows down
+    #[arg(long, value_enum, default_value_t = Easing::EaseOut)]
+    easing: Easing,
+
+    /// Length of a spin in seconds
+    #[arg(long, default_value_t = 6.0)]
+    spin_duration: f64,
+
+    /// Full rotations through the list before the spin lands
+    #[arg(
+        long,
+        default_value_t = 3,
+        value_parser = RangedU64ValueParser::<usize>::new().range(..=MAX_ROTATIONS as u64)
+    )]
+    min_rotations: usize,
+
+    /// How each draw plays out: spin to a winner, or knock everyone else out
+    #[arg(long, value_enum, default_value_t = DrawStyle::Spin)]
+    draw_style: DrawStyle,
+
+    /// How the draw is shown
+    #[arg(long, value_enum, default_value_t = Visual::List)]
+    visual: Visual,
+
+    /// Colour theme: dark, light, high-contrast, colour-blind-safe or one from the themes file
+    /// (no colours if NO_COLOR is set and this is omitted)
+    #[arg(long)]
+    theme: Option<String>,
+
+    /// Extra colour themes (defaults to themes.toml next to the participants file, if present)
+    #[arg(long)]
+    themes_file: Option<PathBuf>,
+
+    /// Event name, logo and other branding for the Home tab (defaults to branding.toml next to
+    /// the participants file, if present)
+    #[arg(long)]
+    branding_file: Option<PathBuf>,
+
+    /// Start in the full-screen presentation view, for projectors
+    #[arg(long)]
+    present: bool,
+
+    /// Skip the confetti and fireworks after each draw
+    #[arg(long)]
+    no_celebration: bool,
+
+    /// Seed for the draws as 64 hex characters (random if omitted)
+    #[arg(long, conflicts_with = "resume")]
+    seed: Option<String>,
+
+    /// Session file that is kept up to date while the app runs
+    #[arg(long, default_value = "raffle-session.json")]
+    session_file: PathBuf,
+
+    /// Pick up the session saved in the session file
+    #[arg(long)]
+    resume: bool,
+
+    /// Start a new session even if the session file holds one that didn't end
+    #[arg(long, conflicts_with = "resume")]
+    new_session: bool,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Rerun a seeded draw and check that it gives the same winners
+    Verify {
+        /// Seed revealed after the draw
+        #[arg(long)]
+        seed: String,
+
+        #[arg(short, long, default_value = "participants.txt")]
+        participants_file: PathBuf,
+
+        /// Format of the participants file (guessed from the extension if omitted)
+        #[arg(short, long, value_enum)]
+        format: Option<Format>,
+
+        /// Add an entry for each guest in a Meetup export
+        #[arg(long)]
+        include_guests: bool,
+
+        /// Merge the duplicates the review suggests, as when the session confirmed the review
+        #[arg(long)]
+        merge_duplicates: bool,
+
+        /// Number of draws to replay (defaults to the number of expected winners, or 1)
+        #[arg(short, long)]
+        draws: Option<usize>,
+
+        /// Replay draws where winners stay in the pool
+        #[arg(long)]
+        with_replacement: bool,
+
+        /// Winners announced during the session, in order
+        expected: Vec<String>,
+    },
 }
 
-fn main() -> AppResult<()> {
+fn main() -> ExitCode {
+    match run(Args::parse()) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("raffle: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> AppResult<ExitCode> {
+    if let Some(Command::Verify {
+        seed,
+        participants_file,
+        format,
+        include_guests,
+        merge_duplicates,
+        draws,
+        with_replacement,
+        expected,
+    }) = &args.command
+    {
+        let draw_mode = match *with_replacement {
+            true => DrawMode::WithReplacement,
+            false => DrawMode::WithoutReplacement,
+        };
+
+        // Read the list exactly like the session did, so the same people are in the draw.
+        let mut import = data::import_participants(participants_file, *format, *include_guests)?;
+        for row in &import.skipped {
+            eprintln!("Skipped {}", row);
+        }
+        if *merge_duplicates {
+            let merged = dedup::merge_suggested(&mut import.participants);
+            eprintln!("Merged {} duplicates", merged);
+        }
+
+        return verify(seed, import.participants, *draws, draw_mode, expected);
+    }
+
     // Create an application.
-    let args = Args::parse();
-    let mut app = match args.participants_file {
-        Some(path) => App::new(&path),
-        None => App::default(),
+    let mut app = match args.resume {
+        true => Session::load(&args.session_file)?.restore()?,
+        false => {
+            if !args.new_session {
+                Session::check_overwrite(&args.session_file)?;
+            }
+            create_app(&args)?
+        }
+    };
+    app.spin_config = SpinConfig {
+        easing: args.easing,
+        duration: Duration::try_from_secs_f64(args.spin_duration)?,
+        min_rotations: args.min_rotations,
+    };
+    app.themes = load_themes(&args)?;
+    let theme = match &args.theme {
+        Some(name) => app.themes.find(name)?.clone(),
+        None if theme::no_color_requested() => Theme::no_color(),
+        None => app.themes.find("dark")?.clone(),
     };
+    app.set_theme(&theme);
+    app.branding = match args
+        .branding_file
+        .clone()
+        .or_else(|| next_to_participants(&args, "branding.toml"))
+    {
+        Some(path) => Branding::load(&path)?,
+        None => Branding::default(),
+    };
+    if theme == Theme::no_color() {
+        app.branding.accent = None;
+    }
+    app.output = args.output.clone();
+    app.output_format = args.output_format;
+    app.draw_style = args.draw_style;
+    app.visual = args.visual;
+    app.presenting = args.present;
+    app.celebrate = !args.no_celebration;
+    app.session_file = Some(args.session_file.clone());
 
     // Initialize the terminal user interface.
     let backend = CrosstermBackend::new(io::stderr());
     let terminal = Terminal::new(backend)?;
-    let events = EventHandler::new(TICK_RATE);
+    let events = EventHandler::new(TICK_RATE, FRAME_RATE);
     let mut tui = Tui::new(terminal, events);
     tui.init()?;
 
     // Start the main loop.
+    let mut animating = false;
+    tui.request_frame();
     while app.running {
-        // Render the user interface.
-        tui.draw(&mut app)?;
         // Handle events.
         match tui.events.next()? {
             Event::Tick => app.tick(),
-            Event::Key(key_event) => handle_key_events(key_event, &mut app)?,
-            Event::Mouse(_) => {}
-            Event::Resize(_, _) => {}
+            Event::Render => {
+                // Bring the animation up to date before drawing it.
+                app.tick();
+                // Render the user interface.
+                tui.draw(&mut app)?;
+            }
+            Event::Key(key_event) => {
+                handle_key_events(key_event, &mut app)?;
+                tui.request_frame();
+            }
+            Event::Paste(text) => {
+                handle_paste_event(&text, &mut app);
+                tui.request_frame();
+            }
+            Event::FocusLost => app.pause_spin(),
+            Event::FocusGained => app.resume_spin(),
+            Event::Mouse(mouse_event) => {
+                handle_mouse_events(mouse_event, &mut app);
+
+                // Plain mouse movement changes nothing, so it isn't worth a frame.
+                if mouse_event.kind != MouseEventKind::Moved {
+                    tui.request_frame();
+                }
+            }
+            Event::Resize(_, _) => tui.request_frame(),
+        }
+
+        // Frames only flow while something moves, plus one to show where it stopped.
+        if app.is_animating() != animating {
+            animating = app.is_animating();
+            tui.set_animating(animating);
+            tui.request_frame();
         }
     }
 
     // Exit the user interface.
     tui.exit()?;
-    Ok(())
+
+    if app.seed_revealed {
+        eprintln!("Commitment: {}", app.commitment());
+        eprintln!("Seed:       {}", app.seed);
+    }
+
+    if let Some(output) = &app.output {
+        export::write(&app, output, app.export_format(output))?;
+        eprintln!("Results written to {}", output.display());
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Creates a fresh application from the participants and prize files.
+fn create_app(args: &Args) -> AppResult<App> {
+    let path = args
+        .participants_file
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("participants.txt"));
+    let import = data::import_participants(&path, args.format, args.include_guests)?;
+    for row in &import.skipped {
+        eprintln!("Skipped {}", row);
+    }
+
+    let mut app = match &args.seed {
+        Some(seed) => App::with_seed(import.participants, Seed::from_hex(seed)?),
+        None => App::with_participants(import.participants),
+    };
+
+    let prizes_file = args
+        .prizes_file
+        .clone()
+        .or_else(|| Some(path.with_file_name("prizes.txt")).filter(|p| p.exists()));
+    if let Some(prizes_file) = prizes_file {
+        app.prizes = data::read_prizes_from_file(&prizes_file)?;
+    }
+
+    app.start_review();
+
+    if !import.skipped.is_empty() {
+        app.status_message = Some(format!(
+            "Skipped {} rows from {}",
+            import.skipped.len(),
+            path.display()
+        ));
+    }
+
+    Ok(app)
+}
+
+/// The file called `name` next to the participants file, if there is one.
+fn next_to_participants(args: &Args, name: &str) -> Option<PathBuf> {
+    let participants_file = args
+        .participants_file
+        .clone()
+        .unwrap_or_else(|| PathBuf::from("participants.txt"));
+
+    Some(participants_file.with_file_name(name)).filter(|p| p.exists())
+}
+
+/// Loads the built-in themes and any from the themes file.
+fn load_themes(args: &Args) -> AppResult<Themes> {
+    let themes_file = args
+        .themes_file
+        .clone()
+        .or_else(|| next_to_participants(args, "themes.toml"));
+
+    match themes_file {
+        Some(path) => Themes::load(&path),
+        None => Ok(Themes::default()),
+    }
+}
+
+/// Replays the draws for `seed` and compares them with the `expected` winners.
+fn verify(
+    seed: &str,
+    participants: Vec<Participant>,
+    draws: Option<usize>,
+    draw_mode: DrawMode,
+    expected: &[String],
+) -> AppResult<ExitCode> {
+    let seed = Seed::from_hex(seed)?;
+
+    let commitment = seed.commitment(&participants);
+    let mut app = App::with_seed(participants, seed);
+    app.draw_mode = draw_mode;
+
+    let draws = draws.unwrap_or(expected.len().max(1));
+    let winners: Vec<String> = (0..draws)
+        .map_while(|_| app.draw_to_completion())
+        .map(|winner| winner.name)
+        .collect();
+
+    println!("Commitment: {}", commitment);
+    println!("Seed:       {}", seed);
+    println!("Drawing {}", draw_mode);
+    for (round, winner) in winners.iter().enumerate() {
+        println!("{:>3}. {}", round + 1, winner);
+    }
+
+    if expected.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if winners == expected {
+        println!("✅ The draw matches the announced winners.");
+        Ok(ExitCode::SUCCESS)
+    } else {
+        println!("❌ The draw does not match the announced winners.");
+        Ok(ExitCode::FAILURE)
+    }
 }
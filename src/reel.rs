@@ -0,0 +1,100 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Modifier, Style},
+    text::Span,
+    widgets::Widget,
+};
+
+/// Rows between two names on the reel. More than one, so the reel can stop halfway.
+const SPACING: f64 = 2.0;
+
+/// Vertical slot-machine reel that scrolls names past a payline in the middle.
+///
+/// The reel wraps around, so the first name follows the last one. Names further from the
+/// payline fade out.
+#[derive(Debug, Clone)]
+pub struct Reel<'a> {
+    names: Vec<&'a str>,
+    /// Which name is on the payline: 2.5 is halfway between the third and fourth.
+    position: f64,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl<'a> Reel<'a> {
+    pub fn new(names: Vec<&'a str>, position: f64) -> Self {
+        Self {
+            names,
+            position,
+            style: Style::default(),
+            highlight_style: Style::default(),
+        }
+    }
+
+    /// Style of the names off the payline.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style of the name on the payline, and of the payline itself.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// How a name `distance` rows from the payline looks.
+    fn faded(&self, distance: f64) -> Style {
+        match distance.abs() {
+            d if d < 0.5 => self.highlight_style.add_modifier(Modifier::BOLD),
+            d if d <= SPACING => self.style,
+            d if d <= SPACING * 2.0 => self.style.add_modifier(Modifier::DIM),
+            _ => self
+                .style
+                .add_modifier(Modifier::DIM)
+                .add_modifier(Modifier::ITALIC),
+        }
+    }
+}
+
+impl Widget for Reel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let count = self.names.len();
+        if count == 0 || area.width < 5 || area.height < 3 {
+            return;
+        }
+
+        let payline = area.y + area.height / 2;
+
+        // The payline is marked on both sides, with room for the names in between.
+        buf.set_string(area.left(), payline, "▶", self.highlight_style);
+        buf.set_string(area.right() - 1, payline, "◀", self.highlight_style);
+        let names_area = Rect {
+            x: area.x + 2,
+            width: area.width - 4,
+            ..area
+        };
+
+        // Every name that fits above and below the payline, wrapping around the reel.
+        let reach = (area.height as f64 / 2.0 / SPACING).ceil() as i64 + 1;
+        let nearest = self.position.round() as i64;
+
+        for slot in nearest - reach..=nearest + reach {
+            let distance = (slot as f64 - self.position) * SPACING;
+            let row = payline as i64 + distance.round() as i64;
+            if row < area.top() as i64 || row >= area.bottom() as i64 {
+                continue;
+            }
+
+            let name: String = self.names[slot.rem_euclid(count as i64) as usize]
+                .chars()
+                .take(names_area.width as usize)
+                .collect();
+            let width = Span::raw(name.as_str()).width() as u16;
+            let x = names_area.x + (names_area.width.saturating_sub(width)) / 2;
+
+            buf.set_string(x, row as u16, name, self.faded(distance));
+        }
+    }
+}
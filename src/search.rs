@@ -0,0 +1,86 @@
+/// A participant that matches the search query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    /// Index into the full participant list.
+    pub index: usize,
+    /// Character positions in the name that matched the query.
+    pub positions: Vec<usize>,
+    pub score: i32,
+}
+
+/// State of the `/` search over the participant list.
+#[derive(Debug, Clone, Default)]
+pub struct Search {
+    /// Selection to go back to when the search is cleared.
+    pub previous_selection: Option<usize>,
+    /// Matches for the current query, best first.
+    pub matches: Vec<SearchMatch>,
+}
+
+impl Search {
+    /// Matches `query` against `names` and sorts the results by score.
+    pub fn update<'a>(&mut self, query: &str, names: impl Iterator<Item = &'a str>) {
+        self.matches = names
+            .enumerate()
+            .filter_map(|(index, name)| {
+                fuzzy_match(query, name).map(|(score, positions)| SearchMatch {
+                    index,
+                    positions,
+                    score,
+                })
+            })
+            .collect();
+
+        // Stable, so equally good matches keep the list order.
+        self.matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    }
+
+    /// Position in [`Search::matches`] of the participant at `index`.
+    pub fn position(&self, index: Option<usize>) -> Option<usize> {
+        let index = index?;
+        self.matches.iter().position(|m| m.index == index)
+    }
+}
+
+/// Matches the characters of `query` in order against `text`, ignoring case.
+///
+/// Returns a score, higher for consecutive characters and word starts, and the matched
+/// character positions. An empty query matches everything.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<(i32, Vec<usize>)> {
+    let mut query = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+
+    let mut score = 0;
+    let mut positions = Vec::new();
+    let mut previous: Option<char> = None;
+
+    for (position, c) in text.chars().enumerate() {
+        let Some(&wanted) = query.peek() else {
+            break;
+        };
+
+        if c.to_lowercase().eq(wanted.to_lowercase()) {
+            score += 1;
+
+            if positions.last() == Some(&position.wrapping_sub(1)) {
+                score += 5;
+            }
+            if previous.is_none_or(|p| !p.is_alphanumeric()) {
+                score += 8;
+            }
+
+            positions.push(position);
+            query.next();
+        }
+
+        previous = Some(c);
+    }
+
+    match query.peek() {
+        Some(_) => None,
+        None => Some((score, positions)),
+    }
+}
@@ -0,0 +1,85 @@
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use sha2::{Digest, Sha256};
+use std::{error::Error, fmt};
+
+use crate::data::Participant;
+
+/// Secret that drives every random choice of a session.
+///
+/// The [`commitment`](Seed::commitment) is shown before the first draw and the seed itself is
+/// revealed when the session ends, so anyone can rerun the draws with `raffle verify`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seed([u8; 32]);
+
+impl Seed {
+    /// Generates a fresh seed from the operating system's entropy source.
+    pub fn random() -> Self {
+        let mut bytes = [0u8; 32];
+        rand::rngs::OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Parses a seed from 64 hexadecimal characters.
+    pub fn from_hex(hex: &str) -> Result<Self, Box<dyn Error>> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err("seed must be 64 hexadecimal characters".into());
+        }
+
+        let mut bytes = [0u8; 32];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16)?;
+        }
+
+        Ok(Self(bytes))
+    }
+
+    pub fn to_hex(&self) -> String {
+        to_hex(&self.0)
+    }
+
+    /// SHA-256 hash of the seed followed by a `name<TAB>tickets` line for each participant,
+    /// safe to publish before the draw.
+    ///
+    /// Covering the list as well as the seed means that whoever knows the seed can't steer
+    /// the draw by quietly adding or removing people afterwards.
+    pub fn commitment(&self, participants: &[Participant]) -> String {
+        let mut hash = Sha256::new().chain_update(self.0);
+        for participant in participants {
+            hash.update(format!("{}\t{}\n", participant.name, participant.tickets));
+        }
+
+        to_hex(&hash.finalize())
+    }
+
+    /// Number for cosmetic randomness, like the confetti after a draw, derived from the seed,
+    /// `purpose` and `index`.
+    ///
+    /// It is hashed from the seed rather than taken from [`rng`](Seed::rng), so it never
+    /// changes who wins.
+    pub fn derive(&self, purpose: &str, index: u64) -> u64 {
+        let hash = Sha256::new()
+            .chain_update(self.0)
+            .chain_update(purpose)
+            .chain_update(index.to_le_bytes())
+            .finalize();
+
+        u64::from_le_bytes(hash[..8].try_into().expect("SHA-256 is 32 bytes"))
+    }
+
+    /// Random number generator that produces the same sequence for the same seed.
+    pub fn rng(&self) -> ChaCha20Rng {
+        ChaCha20Rng::from_seed(self.0)
+    }
+}
+
+impl fmt::Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+use crate::app::{App, AppResult, DrawMode};
+use crate::data::{Participant, Prize, Winner};
+use crate::seed::Seed;
+
+/// Snapshot of everything needed to pick a session back up after a crash.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Session {
+    pub participants: Vec<Participant>,
+    pub selected: Option<usize>,
+    pub winners: Vec<Winner>,
+    pub spin_winner: Option<Participant>,
+    pub prizes: Vec<Prize>,
+    pub draw_mode: DrawMode,
+    pub active_tab: usize,
+    pub seed: String,
+    /// Position in the seeded random stream, so resumed draws continue where they left off.
+    pub rng_word_pos: u128,
+    #[serde(default)]
+    pub published_commitment: Option<String>,
+    #[serde(default)]
+    pub seed_revealed: bool,
+    /// Was the app quit on purpose? Sessions that weren't may still need to be resumed.
+    #[serde(default)]
+    pub ended: bool,
+}
+
+impl Session {
+    pub fn new(app: &App) -> Self {
+        Self {
+            participants: app.all_participants.items.clone(),
+            selected: app.all_participants.state.selected(),
+            winners: app.all_winners.clone(),
+            spin_winner: app.spin_winner.clone(),
+            prizes: app.prizes.clone(),
+            draw_mode: app.draw_mode,
+            active_tab: app.tabs.active,
+            seed: app.seed.to_hex(),
+            rng_word_pos: app.rng.get_word_pos(),
+            published_commitment: app.published_commitment.clone(),
+            seed_revealed: app.seed_revealed,
+            ended: !app.running,
+        }
+    }
+
+    /// Rebuilds the [`App`] the session was taken from.
+    pub fn restore(self) -> AppResult<App> {
+        let mut app = App::with_seed(Vec::new(), Seed::from_hex(&self.seed)?);
+        self.apply(&mut app);
+
+        Ok(app)
+    }
+
+    /// Puts `app` back into the state the session was taken from. The seed is left alone.
+    ///
+    /// A published commitment or revealed seed can't be taken back, so undo never clears them.
+    pub fn apply(self, app: &mut App) {
+        app.all_participants.items = self.participants;
+        app.all_participants.state.select(
+            self.selected
+                .filter(|&i| i < app.all_participants.items.len()),
+        );
+        app.all_winners = self.winners;
+        app.spin_winner = self.spin_winner;
+        app.prizes = self.prizes;
+        app.draw_mode = self.draw_mode;
+        app.tabs.active = self.active_tab % app.tabs.titles.len();
+        app.rng.set_word_pos(self.rng_word_pos);
+        if self.published_commitment.is_some() {
+            app.published_commitment = self.published_commitment;
+        }
+        app.seed_revealed |= self.seed_revealed;
+    }
+
+    /// Makes sure starting a new session won't overwrite one at `path` that didn't end,
+    /// most likely because the app was closed by accident.
+    pub fn check_overwrite(path: &Path) -> AppResult<()> {
+        if !path.exists() || Self::load(path).is_ok_and(|session| session.ended) {
+            return Ok(());
+        }
+
+        Err(format!(
+            "{} holds a session that didn't end, pick it up with --resume or start over with \
+             --new-session",
+            path.display()
+        )
+        .into())
+    }
+
+    pub fn load(path: &Path) -> AppResult<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Writes the session next to `path` first and then moves it into place, so a crash
+    /// halfway through never leaves a truncated file behind.
+    pub fn save(&self, path: &Path) -> AppResult<()> {
+        let partial = path.with_extension("partial");
+
+        fs::write(&partial, serde_json::to_string(self)?)?;
+        fs::rename(partial, path)?;
+        Ok(())
+    }
+}
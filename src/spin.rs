@@ -0,0 +1,101 @@
+use std::time::Duration;
+
+/// How the spinner slows down over the course of a spin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Easing {
+    /// Constant speed, then a sudden stop.
+    Linear,
+    /// Fast start that gradually slows to a crawl.
+    EaseOut,
+    /// Very fast start with a long, slow finish.
+    Exponential,
+}
+
+impl Easing {
+    /// Fraction of the distance covered after `progress` (0 to 1) of the duration.
+    pub fn apply(self, progress: f64) -> f64 {
+        let progress = progress.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => progress,
+            Easing::EaseOut => 1.0 - (1.0 - progress).powi(3),
+            Easing::Exponential => (1.0 - 2_f64.powf(-10.0 * progress)) / (1.0 - 2_f64.powi(-10)),
+        }
+    }
+}
+
+/// How a draw is shown in the spin area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Visual {
+    /// The highlight moving through the participant list.
+    List,
+    /// A wheel of fortune turning under a fixed pointer.
+    Wheel,
+    /// A slot-machine reel scrolling names past a payline.
+    Reel,
+}
+
+impl Visual {
+    /// The visual after this one, wrapping around.
+    pub fn next(self) -> Self {
+        match self {
+            Visual::List => Visual::Wheel,
+            Visual::Wheel => Visual::Reel,
+            Visual::Reel => Visual::List,
+        }
+    }
+}
+
+impl std::fmt::Display for Visual {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Visual::List => write!(f, "list"),
+            Visual::Wheel => write!(f, "wheel"),
+            Visual::Reel => write!(f, "reel"),
+        }
+    }
+}
+
+/// Most full rotations a spin makes before landing, however many are asked for.
+pub const MAX_ROTATIONS: usize = 1000;
+
+/// Timing of the spin animation.
+///
+/// Only the animation is affected: the landing spot is picked before the spin starts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpinConfig {
+    pub easing: Easing,
+    /// How long a spin takes from start to winner.
+    pub duration: Duration,
+    /// Full rotations through the list before landing. Up to two more are added at random.
+    /// Capped at [`MAX_ROTATIONS`].
+    pub min_rotations: usize,
+}
+
+impl Default for SpinConfig {
+    fn default() -> Self {
+        Self {
+            easing: Easing::EaseOut,
+            duration: Duration::from_secs(6),
+            min_rotations: 3,
+        }
+    }
+}
+
+impl SpinConfig {
+    /// Number of the `total` steps that should have been taken `elapsed` into the spin.
+    pub fn steps_due(&self, total: usize, elapsed: Duration) -> usize {
+        (self.position(total, elapsed) as usize).min(total)
+    }
+
+    /// Like [`steps_due`](SpinConfig::steps_due), but including how far into the next step
+    /// the spin is, for visuals that move smoothly between steps.
+    pub fn position(&self, total: usize, elapsed: Duration) -> f64 {
+        if elapsed >= self.duration {
+            return total as f64;
+        }
+
+        let progress = elapsed.as_secs_f64() / self.duration.as_secs_f64();
+        total as f64 * self.easing.apply(progress)
+    }
+}
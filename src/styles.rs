@@ -1,7 +1,22 @@
 use ratatui::{
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::Padding,
 };
+use std::sync::RwLock;
+
+use crate::theme::{Palette, Theme};
+
+/// Colours of the current theme, shared by every style below.
+static PALETTE: RwLock<Palette> = RwLock::new(Palette::DARK);
+
+/// Switches every style to the colours of `theme`.
+pub fn set_theme(theme: &Theme) {
+    *PALETTE.write().unwrap_or_else(|e| e.into_inner()) = theme.palette;
+}
+
+fn palette() -> Palette {
+    *PALETTE.read().unwrap_or_else(|e| e.into_inner())
+}
 
 pub const LOGO: &str = r#"
 
@@ -14,6 +29,7 @@ pub const LOGO: &str = r#"
 
 "#;
 
+/// Ferris with the Copenhagen Rust Community, the mascot of the default branding.
 pub const FERRIS: &str = r#"
  _____________________________
 /         Made for the        \
@@ -27,6 +43,13 @@ pub const FERRIS: &str = r#"
           / '-----' \
 "#;
 
+/// Ferris on its own, the mascot of a branding file that doesn't bring its own.
+pub const CRAB: &str = r#"    _~^~^~_
+\) /  o o  \ (/
+  '_   -   _'
+  / '-----' \
+"#;
+
 pub const PADDING: Padding = Padding {
     left: 2,
     right: 2,
@@ -35,7 +58,7 @@ pub const PADDING: Padding = Padding {
 };
 
 pub fn action() -> Style {
-    Style::default().fg(Color::LightCyan)
+    Style::default().fg(palette().action)
 }
 
 pub fn action_highlight() -> Style {
@@ -45,7 +68,7 @@ pub fn action_highlight() -> Style {
 }
 
 pub fn winner() -> Style {
-    action().fg(Color::LightGreen)
+    action().fg(palette().winner)
 }
 
 pub fn winner_highlight() -> Style {
@@ -55,11 +78,19 @@ pub fn winner_highlight() -> Style {
 }
 
 pub fn key() -> Style {
-    action().add_modifier(Modifier::UNDERLINED)
+    Style::default()
+        .fg(palette().key)
+        .add_modifier(Modifier::UNDERLINED)
+}
+
+pub fn search_match() -> Style {
+    orange()
+        .add_modifier(Modifier::BOLD)
+        .add_modifier(Modifier::UNDERLINED)
 }
 
 pub fn spin() -> Style {
-    Style::default().fg(Color::LightYellow)
+    Style::default().fg(palette().spin)
 }
 
 pub fn spin_highlight() -> Style {
@@ -69,11 +100,17 @@ pub fn spin_highlight() -> Style {
 }
 
 pub fn orange() -> Style {
-    Style::default().fg(Color::Indexed(214))
+    Style::default().fg(palette().orange)
 }
 
 pub fn secondary() -> Style {
     Style::default()
-        .fg(Color::Gray)
+        .fg(palette().secondary)
         .add_modifier(Modifier::ITALIC)
 }
+
+pub fn knocked_out() -> Style {
+    Style::default()
+        .fg(palette().secondary)
+        .add_modifier(Modifier::CROSSED_OUT)
+}
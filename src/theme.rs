@@ -0,0 +1,253 @@
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fs, path::Path, str::FromStr};
+
+use crate::app::AppResult;
+
+/// The colours every style is built from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Borders, highlights and other things you can act on.
+    pub action: Color,
+    /// Winners and the celebration around them.
+    pub winner: Color,
+    /// The spinner while it is moving.
+    pub spin: Color,
+    /// Key names in the help.
+    pub key: Color,
+    /// Participant names and other accents.
+    pub orange: Color,
+    /// Labels and hints that should stay in the background.
+    pub secondary: Color,
+}
+
+impl Palette {
+    /// The original colours, made for dark terminals.
+    pub const DARK: Palette = Palette {
+        action: Color::LightCyan,
+        winner: Color::LightGreen,
+        spin: Color::LightYellow,
+        key: Color::LightCyan,
+        orange: Color::Indexed(214),
+        secondary: Color::Gray,
+    };
+
+    /// Darker colours that stay readable on a white background.
+    pub const LIGHT: Palette = Palette {
+        action: Color::Blue,
+        winner: Color::Green,
+        spin: Color::Magenta,
+        key: Color::Blue,
+        orange: Color::Indexed(130),
+        secondary: Color::DarkGray,
+    };
+
+    /// Only the brightest colours, for washed-out projectors.
+    pub const HIGH_CONTRAST: Palette = Palette {
+        action: Color::White,
+        winner: Color::LightGreen,
+        spin: Color::LightYellow,
+        key: Color::LightYellow,
+        orange: Color::White,
+        secondary: Color::White,
+    };
+
+    /// The Okabe–Ito colours, which stay apart for the common kinds of colour blindness.
+    pub const COLOUR_BLIND_SAFE: Palette = Palette {
+        action: Color::Rgb(86, 180, 233),
+        winner: Color::Rgb(0, 158, 115),
+        spin: Color::Rgb(240, 228, 66),
+        key: Color::Rgb(86, 180, 233),
+        orange: Color::Rgb(230, 159, 0),
+        secondary: Color::Gray,
+    };
+
+    /// The terminal's own colours, for `NO_COLOR`. Bold, underlined and reversed text still
+    /// mark what is highlighted.
+    pub const NO_COLOR: Palette = Palette {
+        action: Color::Reset,
+        winner: Color::Reset,
+        spin: Color::Reset,
+        key: Color::Reset,
+        orange: Color::Reset,
+        secondary: Color::Reset,
+    };
+}
+
+/// A named palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    pub name: String,
+    pub palette: Palette,
+}
+
+impl Theme {
+    pub fn new(name: &str, palette: Palette) -> Self {
+        Self {
+            name: name.to_string(),
+            palette,
+        }
+    }
+
+    /// The theme used when `NO_COLOR` is set.
+    pub fn no_color() -> Self {
+        Self::new("no-color", Palette::NO_COLOR)
+    }
+}
+
+/// Asks for no colours, as described on <https://no-color.org>: set and not empty.
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// The themes to choose from, in the order they are cycled through.
+#[derive(Debug, Clone)]
+pub struct Themes {
+    themes: Vec<Theme>,
+}
+
+impl Default for Themes {
+    /// Just the built-in themes.
+    fn default() -> Self {
+        Self {
+            themes: vec![
+                Theme::new("dark", Palette::DARK),
+                Theme::new("light", Palette::LIGHT),
+                Theme::new("high-contrast", Palette::HIGH_CONTRAST),
+                Theme::new("colour-blind-safe", Palette::COLOUR_BLIND_SAFE),
+            ],
+        }
+    }
+}
+
+/// A theme as written in a themes file. Colours that are left out come from `base`, one of
+/// the built-in themes.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeConfig {
+    base: Option<String>,
+    action: Option<ColorValue>,
+    winner: Option<ColorValue>,
+    spin: Option<ColorValue>,
+    key: Option<ColorValue>,
+    orange: Option<ColorValue>,
+    secondary: Option<ColorValue>,
+}
+
+/// A colour name, `#rrggbb`, or an index into the 256-colour palette.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum ColorValue {
+    Index(u8),
+    Text(String),
+}
+
+impl ColorValue {
+    pub(crate) fn parse(&self) -> Result<Color, String> {
+        match self {
+            ColorValue::Index(index) => Ok(Color::Indexed(*index)),
+            ColorValue::Text(text) => Color::from_str(text).map_err(|_| {
+                format!(
+                    "unknown colour '{}', use a name like 'light blue', '#rrggbb' or 0-255",
+                    text
+                )
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemesFile {
+    #[serde(default)]
+    themes: BTreeMap<String, ThemeConfig>,
+}
+
+impl Themes {
+    /// The built-in themes plus the ones in the TOML file at `path`.
+    ///
+    /// A theme in the file with the name of a built-in one replaces it.
+    pub fn load(path: &Path) -> AppResult<Self> {
+        let contents =
+            fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        Self::parse(&contents).map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    /// The built-in themes plus the ones in `toml`.
+    pub fn parse(toml: &str) -> AppResult<Self> {
+        let file: ThemesFile = toml::from_str(toml)?;
+        let builtin = Self::default();
+        let mut themes = Self::default();
+
+        for (name, config) in file.themes {
+            let base = config.base.as_deref().unwrap_or("dark");
+            let mut palette = builtin
+                .get(base)
+                .ok_or_else(|| {
+                    format!(
+                        "theme '{}' is based on '{}', which is not a built-in theme",
+                        name, base
+                    )
+                })?
+                .palette;
+
+            for (colour, value) in [
+                (&mut palette.action, &config.action),
+                (&mut palette.winner, &config.winner),
+                (&mut palette.spin, &config.spin),
+                (&mut palette.key, &config.key),
+                (&mut palette.orange, &config.orange),
+                (&mut palette.secondary, &config.secondary),
+            ] {
+                if let Some(value) = value {
+                    *colour = value
+                        .parse()
+                        .map_err(|e| format!("theme '{}': {}", name, e))?;
+                }
+            }
+
+            match themes.themes.iter_mut().find(|theme| theme.name == name) {
+                Some(theme) => theme.palette = palette,
+                None => themes.themes.push(Theme { name, palette }),
+            }
+        }
+
+        Ok(themes)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Theme> {
+        self.themes.iter().find(|theme| theme.name == name)
+    }
+
+    /// Looks up `name`, listing the available themes if there is none by that name.
+    pub fn find(&self, name: &str) -> AppResult<&Theme> {
+        self.get(name).ok_or_else(|| {
+            format!(
+                "unknown theme '{}', choose one of {}",
+                name,
+                self.names().join(", ")
+            )
+            .into()
+        })
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.themes
+            .iter()
+            .map(|theme| theme.name.as_str())
+            .collect()
+    }
+
+    /// The theme after the one called `name`, wrapping around. Starts from the first theme
+    /// when `name` isn't in the list, such as for [`Theme::no_color`].
+    pub fn after(&self, name: &str) -> &Theme {
+        let next = self
+            .themes
+            .iter()
+            .position(|theme| theme.name == name)
+            .map_or(0, |i| (i + 1) % self.themes.len());
+
+        &self.themes[next]
+    }
+}
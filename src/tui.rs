@@ -1,41 +1,92 @@
 use crate::app::{App, AppResult};
 use crate::event::EventHandler;
 use crate::ui;
-use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+use crossterm::cursor::Show;
+use crossterm::event::{
+    DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+    EnableFocusChange, EnableMouseCapture,
+};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
 use ratatui::backend::Backend;
 use ratatui::Terminal;
-use std::io;
+use std::io::{self, Write};
+use std::panic;
+use std::sync::Once;
+
+/// Exit code used when the process is stopped by a signal.
+const SIGNAL_EXIT_CODE: i32 = 130;
 
 /// Representation of a terminal user interface.
 ///
 /// It is responsible for setting up the terminal,
 /// initializing the interface and handling the draw events.
+///
+/// The terminal is restored on [`exit`](Tui::exit), on panic, on SIGINT/SIGTERM/SIGHUP and,
+/// as a last resort, when the [`Tui`] is dropped.
 #[derive(Debug)]
-pub struct Tui<B: Backend> {
+pub struct Tui<B: Backend, W: Write = io::Stderr> {
     /// Interface to the Terminal.
     terminal: Terminal<B>,
     /// Terminal event handler.
     pub events: EventHandler,
+    /// Where the escape sequences that set up and restore the terminal are written.
+    output: W,
+    /// Is the terminal in raw mode and on the alternate screen?
+    active: bool,
 }
 
 impl<B: Backend> Tui<B> {
     /// Constructs a new instance of [`Tui`].
     pub fn new(terminal: Terminal<B>, events: EventHandler) -> Self {
-        Self { terminal, events }
+        Self::with_output(terminal, events, io::stderr())
+    }
+}
+
+impl<B: Backend, W: Write> Tui<B, W> {
+    /// Constructs a [`Tui`] that sets up and restores the terminal through `output`.
+    pub fn with_output(terminal: Terminal<B>, events: EventHandler, output: W) -> Self {
+        Self {
+            terminal,
+            events,
+            output,
+            active: false,
+        }
     }
 
     /// Initializes the terminal interface.
     ///
     /// It enables the raw mode and sets terminal properties.
     pub fn init(&mut self) -> AppResult<()> {
+        install_panic_hook();
+        install_signal_handler()?;
+
         terminal::enable_raw_mode()?;
-        crossterm::execute!(io::stderr(), EnterAlternateScreen, EnableMouseCapture)?;
+        self.enter()?;
+
         self.terminal.hide_cursor()?;
         self.terminal.clear()?;
         Ok(())
     }
 
+    /// Switches to the alternate screen and turns on mouse, paste and focus events. From
+    /// here on the terminal is restored however the [`Tui`] goes away.
+    pub fn enter(&mut self) -> AppResult<()> {
+        crossterm::execute!(
+            self.output,
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableBracketedPaste,
+            EnableFocusChange
+        )?;
+        self.active = true;
+        Ok(())
+    }
+
+    /// Has the terminal been set up and not restored yet?
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
     /// [`Draw`] the terminal interface by [`rendering`] the widgets.
     ///
     /// [`Draw`]: ratatui::Terminal::draw
@@ -45,13 +96,81 @@ impl<B: Backend> Tui<B> {
         Ok(())
     }
 
+    /// Asks for a single frame to be drawn.
+    pub fn request_frame(&self) {
+        self.events.request_frame();
+    }
+
+    /// Keeps frames coming at the frame rate while something is moving on screen.
+    pub fn set_animating(&self, animating: bool) {
+        self.events.set_animating(animating);
+    }
+
     /// Exits the terminal interface.
     ///
     /// It disables the raw mode and reverts back the terminal properties.
     pub fn exit(&mut self) -> AppResult<()> {
+        self.active = false;
         terminal::disable_raw_mode()?;
-        crossterm::execute!(io::stderr(), LeaveAlternateScreen, DisableMouseCapture)?;
+        write_restore_sequence(&mut self.output)?;
         self.terminal.show_cursor()?;
         Ok(())
     }
 }
+
+impl<B: Backend, W: Write> Drop for Tui<B, W> {
+    fn drop(&mut self) {
+        if self.active {
+            // Nothing sensible can be done about an error while dropping.
+            let _ = self.exit();
+        }
+    }
+}
+
+/// Leaves raw mode and the alternate screen and shows the cursor again.
+///
+/// Doesn't need a [`Tui`], so it can be called from a panic hook or signal handler.
+pub fn restore_terminal() -> AppResult<()> {
+    terminal::disable_raw_mode()?;
+    write_restore_sequence(&mut io::stderr())
+}
+
+/// Writes the escape sequences that undo [`Tui::init`] to `writer`.
+pub fn write_restore_sequence(writer: &mut impl Write) -> AppResult<()> {
+    crossterm::execute!(
+        writer,
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        DisableFocusChange,
+        Show
+    )?;
+    Ok(())
+}
+
+/// Restores the terminal before the panic message is printed, so it can be read.
+pub fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let report = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let _ = restore_terminal();
+            report(info);
+        }));
+    });
+}
+
+/// Restores the terminal and exits when the process is asked to stop.
+fn install_signal_handler() -> AppResult<()> {
+    let handler = ctrlc::set_handler(|| {
+        let _ = restore_terminal();
+        std::process::exit(SIGNAL_EXIT_CODE);
+    });
+
+    match handler {
+        // Already installed by an earlier `init`.
+        Err(ctrlc::Error::MultipleHandlers) => Ok(()),
+        result => Ok(result?),
+    }
+}
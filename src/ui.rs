@@ -1,14 +1,83 @@
-use crate::{app::App, styles};
+use crate::{
+    app::{App, InputMode},
+    bigtext::BigText,
+    data::Participant,
+    reel::Reel,
+    spin::Visual,
+    styles,
+    wheel::{Slice, Wheel},
+};
 use ratatui::{
     backend::Backend,
-    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Margin, Rect},
+    style::Style,
     text::{Line, Span, Text},
-    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Padding, Paragraph, Tabs, Wrap},
+    widgets::{
+        Block, BorderType, Borders, Clear, List, ListItem, ListState, Padding, Paragraph, Tabs,
+        Wrap,
+    },
     Frame,
 };
+use std::time::Instant;
+
+/// Something on screen that reacts to the mouse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// A tab in the header, by index.
+    Tab(usize),
+    /// The participant list, for scrolling.
+    List,
+    /// A row of the participant list, by index into all participants.
+    Participant(usize),
+    /// The "Spin" button in the spin modal.
+    SpinButton,
+    /// The "Reset" button in the spin modal.
+    ResetButton,
+}
+
+/// Where the mouse targets were drawn in the last frame.
+#[derive(Debug, Default, Clone)]
+pub struct HitAreas {
+    targets: Vec<(Rect, Target)>,
+}
+
+impl HitAreas {
+    /// Forgets the targets of the previous frame.
+    pub fn clear(&mut self) {
+        self.targets.clear();
+    }
+
+    /// Registers `target` at `area`. Targets added later are on top.
+    pub fn add(&mut self, area: Rect, target: Target) {
+        self.targets.push((area, target));
+    }
+
+    /// The topmost target at the given cell, if any.
+    pub fn hit(&self, column: u16, row: u16) -> Option<Target> {
+        self.targets
+            .iter()
+            .rev()
+            .find(|(area, _)| {
+                (area.left()..area.right()).contains(&column)
+                    && (area.top()..area.bottom()).contains(&row)
+            })
+            .map(|(_, target)| *target)
+    }
+}
 
 pub fn render<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>) {
     let size = frame.size();
+    app.hit_areas.clear();
+
+    if app.presenting && app.review.is_none() {
+        render_presentation(app, frame, size);
+        render_celebration(app, frame, size);
+
+        if !matches!(app.input_mode, InputMode::Normal | InputMode::Search) {
+            render_input(app, frame, size);
+        }
+        return;
+    }
 
     let panes = Layout::default()
         .direction(Direction::Vertical)
@@ -30,32 +99,98 @@ pub fn render<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>) {
         .highlight_style(styles::action_highlight());
 
     frame.render_widget(tabs, panes[0]);
+    add_tab_targets(app, panes[0]);
+
+    if app.review.is_some() {
+        render_review(app, frame, panes[1]);
+        return;
+    }
 
     match app.tabs.active {
         0 => render_tab_1(app, frame, panes[1]),
         1 => render_tab_2(app, frame, panes[1]),
         _ => (),
     };
+
+    render_celebration(app, frame, size);
+
+    if !matches!(app.input_mode, InputMode::Normal | InputMode::Search) {
+        render_input(app, frame, panes[1]);
+    }
+}
+
+/// Confetti and fireworks over everything else, while a winner is being celebrated.
+fn render_celebration<B: Backend>(app: &App, frame: &mut Frame<'_, B>, area: Rect) {
+    if let Some(celebration) = &app.celebration {
+        frame.render_widget(celebration, area);
+    }
 }
 
-pub fn render_tab_1<B: Backend>(_app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
-    let mut text = Text::from(styles::LOGO);
+/// Registers the header tabs where [`Tabs`] draws them: padded by a space on each side and
+/// separated by a one-column divider.
+fn add_tab_targets(app: &mut App, area: Rect) {
+    let mut x = area.left();
+
+    for (index, title) in app.tabs.titles.iter().enumerate() {
+        x = x.saturating_add(1);
+        let width =
+            (Span::raw(title.as_str()).width() as u16 + 2).min(area.right().saturating_sub(x));
+        if width == 0 {
+            break;
+        }
+
+        app.hit_areas.add(
+            Rect {
+                x,
+                y: area.top(),
+                width,
+                height: 1,
+            },
+            Target::Tab(index),
+        );
+        x = x.saturating_add(width + 2);
+    }
+}
 
-    text.patch_style(styles::winner());
+pub fn render_tab_1<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
+    let branding = &app.branding;
+    let mut text = Text::from(art(&branding.logo, styles::winner()));
 
     text.extend(vec![
         Line::from(""),
         Line::from(Span::styled(
-            "( Rapidly Assembled Ferris Fortune Locator Engine )",
+            format!("( {} )", branding.tagline),
             styles::secondary(),
         )),
         Line::from(""),
         Line::from(""),
         Line::from("Made for the"),
         Line::from(Span::styled(
-            "Copenhagen Rust Community 🦀🧡",
-            styles::orange(),
+            branding.event_name.clone(),
+            match branding.accent {
+                Some(accent) => styles::orange().fg(accent),
+                None => styles::orange(),
+            },
         )),
+    ]);
+
+    if let Some(date) = &branding.date {
+        text.extend(vec![Line::from(Span::styled(
+            date.clone(),
+            styles::secondary(),
+        ))]);
+    }
+    if let Some(organiser) = &branding.organiser {
+        text.extend(vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Organised by ", styles::secondary()),
+                Span::raw(organiser.clone()),
+            ]),
+        ]);
+    }
+
+    let footer = vec![
         Line::from(""),
         Line::from(""),
         Line::from(""),
@@ -66,17 +201,48 @@ pub fn render_tab_1<B: Backend>(_app: &mut App, frame: &mut Frame<'_, B>, area:
             Span::styled("Q", styles::key()),
             Span::raw(" to exit."),
         ]),
-    ]);
+    ];
+
+    // The mascot is left out rather than pushing the instructions off the screen.
+    if let Some(mascot) = &branding.mascot {
+        let mascot = art(mascot, styles::orange());
+        let padding = styles::PADDING.top + styles::PADDING.bottom;
+
+        // One blank line goes above the mascot.
+        let height = text.height() + 1 + mascot.len() + footer.len();
+
+        if height <= area.height.saturating_sub(padding) as usize {
+            text.extend(vec![Line::from("")]);
+            text.extend(mascot);
+        }
+    }
+    text.extend(footer);
 
     frame.render_widget(
         Paragraph::new(text)
             .alignment(Alignment::Center)
-            .wrap(Wrap { trim: true })
+            .wrap(Wrap { trim: false })
             .block(Block::default().padding(styles::PADDING)),
         area,
     );
 }
 
+/// ASCII art as lines of equal width, so centring them keeps the picture intact.
+fn art(text: &str, style: Style) -> Vec<Line<'static>> {
+    let width = text
+        .lines()
+        .map(|line| Span::raw(line).width())
+        .max()
+        .unwrap_or(0);
+
+    text.lines()
+        .map(|line| {
+            let padding = " ".repeat(width - Span::raw(line).width());
+            Line::from(Span::styled(format!("{}{}", line, padding), style))
+        })
+        .collect()
+}
+
 pub fn render_tab_2<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
     let layout = Layout::default()
         .direction(Direction::Horizontal)
@@ -95,50 +261,228 @@ pub fn render_tab_2<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: R
 }
 
 pub fn render_list<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
-    let mut list_items: Vec<_> = app
-        .all_participants
+    let mut area = area;
+    let mut title = " All participants ".to_string();
+
+    // While searching, only the matches are listed and the selection is tracked separately.
+    let mut search_state = ListState::default();
+    let mut list_items: Vec<_> = match &app.search {
+        Some(search) => {
+            let search_box = Rect { height: 3, ..area };
+            area = Rect {
+                y: area.y + search_box.height,
+                height: area.height.saturating_sub(search_box.height),
+                ..area
+            };
+            render_search(app, frame, search_box);
+
+            title = format!(
+                " {} of {} participants ",
+                search.matches.len(),
+                app.all_participants.items.len()
+            );
+            search_state.select(search.position(app.all_participants.state.selected()));
+
+            search
+                .matches
+                .iter()
+                .map(|m| {
+                    participant_item(
+                        &app.all_participants.items[m.index],
+                        &m.positions,
+                        app.is_knocked_out(m.index),
+                    )
+                })
+                .collect()
+        }
+        None => app
+            .all_participants
+            .items
+            .iter()
+            .enumerate()
+            .map(|(i, participant)| participant_item(participant, &[], app.is_knocked_out(i)))
+            .collect(),
+    };
+
+    if list_items.is_empty() {
+        list_items.push(ListItem::new(" No participants. "))
+    }
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .padding(Padding {
+            left: 0,
+            right: 0,
+            top: 1,
+            bottom: 1,
+        });
+    let inner = block.inner(area);
+
+    let list = List::new(list_items).block(block).highlight_style({
+        let winner_higlighted = { app.all_participants.get_selected() == app.spin_winner };
+
+        if winner_higlighted {
+            styles::winner_highlight()
+        } else if app.is_spinning {
+            styles::spin_highlight()
+        } else {
+            styles::action_highlight()
+        }
+    });
+
+    let offset = match app.search {
+        Some(_) => {
+            frame.render_stateful_widget(list, area, &mut search_state);
+            search_state.offset()
+        }
+        None => {
+            frame.render_stateful_widget(list, area, &mut app.all_participants.state);
+            app.all_participants.state.offset()
+        }
+    };
+
+    // Every row is one line high, so rows map straight onto the visible slice of the list.
+    app.hit_areas.add(area, Target::List);
+
+    let indices: Vec<usize> = match &app.search {
+        Some(search) => search.matches.iter().map(|m| m.index).collect(),
+        None => (0..app.all_participants.items.len()).collect(),
+    };
+
+    for (row, index) in indices
+        .into_iter()
+        .skip(offset)
+        .take(inner.height as usize)
+        .enumerate()
+    {
+        app.hit_areas.add(
+            Rect {
+                y: inner.y + row as u16,
+                height: 1,
+                ..inner
+            },
+            Target::Participant(index),
+        );
+    }
+}
+
+/// Screen that lists possible duplicates so merges can be confirmed before the draw.
+pub fn render_review<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
+    let Some(review) = &mut app.review else {
+        return;
+    };
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(4), Constraint::Min(0)].as_ref())
+        .split(area);
+
+    let intro = Text::from(vec![
+        Line::from("These participants look like they are on the list more than once."),
+        Line::from(vec![
+            Span::styled("Space", styles::key()),
+            Span::raw(" to toggle a merge, "),
+            Span::styled("Enter", styles::key()),
+            Span::raw(" to merge the ticked ones, "),
+            Span::styled("Esc", styles::key()),
+            Span::raw(" to keep everyone."),
+        ]),
+    ]);
+    frame.render_widget(Paragraph::new(intro).wrap(Wrap { trim: true }), layout[0]);
+
+    let participants = &app.all_participants.items;
+    let items: Vec<ListItem> = review
         .items
         .iter()
-        .map(|participant| {
-            let item = ListItem::new(format!(" {} ", participant));
+        .map(|duplicate| {
+            let checkbox = match duplicate.merge {
+                true => " [x] ",
+                false => " [ ] ",
+            };
 
-            if participant.is_winner {
-                item.style(styles::winner())
-            } else {
-                item.style(styles::orange())
-            }
+            ListItem::new(Line::from(vec![
+                Span::styled(checkbox, styles::action()),
+                Span::styled(
+                    participants[duplicate.duplicate].name.clone(),
+                    styles::orange(),
+                ),
+                Span::raw(" into "),
+                Span::styled(
+                    participants[duplicate.original].name.clone(),
+                    styles::winner(),
+                ),
+                Span::styled(format!("  ({})", duplicate.kind), styles::secondary()),
+            ]))
         })
         .collect();
 
-    if app.all_participants.items.is_empty() {
-        list_items.push(ListItem::new(" No participants. "))
-    }
-
-    let list = List::new(list_items)
+    let list = List::new(items)
         .block(
             Block::default()
-                .title(" All participants ")
+                .title(" Possible duplicates ")
                 .borders(Borders::ALL)
-                .padding(Padding {
-                    left: 0,
-                    right: 0,
-                    top: 1,
-                    bottom: 1,
-                }),
+                .padding(styles::PADDING),
         )
-        .highlight_style({
-            let winner_higlighted = { app.all_participants.get_selected() == app.spin_winner };
-
-            if winner_higlighted {
-                styles::winner_highlight()
-            } else if app.is_spinning {
-                styles::spin_highlight()
-            } else {
-                styles::action_highlight()
-            }
-        });
+        .highlight_style(styles::action_highlight());
 
-    frame.render_stateful_widget(list, area, &mut app.all_participants.state);
+    frame.render_stateful_widget(list, layout[1], &mut review.state);
+}
+
+/// A row of the participant list, with the characters at `highlighted` picked out.
+fn participant_item<'a>(
+    participant: &Participant,
+    highlighted: &[usize],
+    knocked_out: bool,
+) -> ListItem<'a> {
+    let style = match (participant.is_winner, knocked_out) {
+        (true, _) => styles::winner(),
+        (false, true) => styles::knocked_out(),
+        (false, false) => styles::orange(),
+    };
+
+    let mut spans = vec![Span::raw(match participant.is_winner {
+        true => " 🎁 ",
+        false => " ",
+    })];
+
+    for (position, c) in participant.name.chars().enumerate() {
+        match highlighted.contains(&position) {
+            true => spans.push(Span::styled(c.to_string(), styles::search_match())),
+            false => spans.push(Span::raw(c.to_string())),
+        }
+    }
+
+    spans.push(Span::raw(match participant.tickets {
+        1 => " ".to_string(),
+        tickets => format!(" ×{} ", tickets),
+    }));
+
+    ListItem::new(Line::from(spans)).style(style)
+}
+
+/// The query box above the list while searching.
+fn render_search<B: Backend>(app: &App, frame: &mut Frame<'_, B>, area: Rect) {
+    let block = Block::default()
+        .title(" Search ")
+        .borders(Borders::ALL)
+        .style(styles::action());
+    let inner = block.inner(area);
+
+    let column = Span::raw(app.input.before_cursor()).width() as u16;
+    let scroll = column.saturating_sub(inner.width.saturating_sub(1));
+
+    frame.render_widget(
+        Paragraph::new(app.input.value.as_str())
+            .style(styles::orange())
+            .scroll((0, scroll))
+            .block(block),
+        area,
+    );
+
+    if inner.width > 0 && inner.height > 0 {
+        frame.set_cursor(inner.x + column - scroll, inner.y);
+    }
 }
 
 pub fn render_spin<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
@@ -147,52 +491,419 @@ pub fn render_spin<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Re
         return;
     }
 
-    // State: Ready
-    let mut modal_content: Paragraph<'_>;
-    let mut modal_text = Text::from("\nReady to roll 🎲");
+    if app.visual != Visual::List {
+        render_visual(app, frame, area);
+        render_seed(app, frame, area);
+        return;
+    }
 
-    // State: Spinning
-    if app.is_spinning {
-        modal_text = Text::from(vec![
-            Line::from(""),
-            Line::from(Span::styled("*spinning wheel noises*", styles::spin())),
-        ]);
+    let modal = create_modal(40, 50, area);
+    frame.render_widget(Clear, modal);
+
+    // State: Winner found
+    if let Some(winner) = &app.spin_winner {
+        let mut caption = Vec::new();
+
+        if let Some(prize) = app.all_winners.last().and_then(|w| w.prize.as_ref()) {
+            caption.extend(vec![
+                Line::from(Span::styled("wins", styles::secondary())),
+                Line::from(prize.to_string()),
+            ]);
+        }
+        caption.extend(vec![Line::from(""), Line::from("🎉🎉🎉")]);
+
+        let block = Block::default()
+            .title("  The winner is  ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Thick)
+            .style(styles::winner());
+
+        let inner = block.inner(modal);
+        frame.render_widget(block, modal);
+
+        render_headline(frame, inner, &winner.name, styles::winner(), caption);
+    } else {
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title("  Spin the wheel  ")
+            .title_alignment(Alignment::Center)
+            .padding(styles::PADDING);
+        let inner = block.inner(modal);
+        frame.render_widget(block, modal);
+
+        // State: Last one standing
+        if let Some(standing) = app.still_standing() {
+            let caption = vec![Line::from(Span::styled(
+                "Last one standing wins",
+                styles::secondary(),
+            ))];
+
+            render_headline(
+                frame,
+                inner,
+                &format!("{} left", standing),
+                styles::spin(),
+                caption,
+            );
+        // State: Spinning
+        } else if app.is_spinning {
+            let name = app
+                .all_participants
+                .get_selected()
+                .map(|participant| participant.name)
+                .unwrap_or_default();
+            let caption = vec![Line::from(Span::styled(
+                "*spinning wheel noises*",
+                styles::spin(),
+            ))];
+
+            render_headline(frame, inner, &name, styles::spin(), caption);
+        } else {
+            // State: Ready
+            let mut modal_text = Text::from("\nReady to roll 🎲");
+
+            if let Some(prize) = app.current_prize() {
+                modal_text.extend(vec![
+                    Line::from(""),
+                    Line::from(Span::styled("Up next", styles::secondary())),
+                    Line::from(Span::styled(prize.to_string(), styles::orange())),
+                ]);
+            } else if app.prizes_exhausted() {
+                modal_text = Text::from("\nAll prizes have been given away 🎁");
+            }
+
+            frame.render_widget(
+                Paragraph::new(modal_text).alignment(Alignment::Center),
+                inner,
+            );
+        }
     }
 
-    modal_content = Paragraph::new(modal_text)
-        .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("  Spin the wheel  ")
-                .title_alignment(Alignment::Center)
-                .padding(styles::PADDING),
+    render_spin_buttons(app, frame, modal);
+
+    render_seed(app, frame, area);
+}
+
+/// The draw as a wheel of fortune or a slot-machine reel, with the state of the draw
+/// underneath.
+fn render_visual<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
+    // Leave the bottom rows to the seed.
+    let area = Rect {
+        height: area.height.saturating_sub(2),
+        ..area
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("  Spin the wheel  ")
+        .title_alignment(Alignment::Center);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let items = &app.all_participants.items;
+    let selected = app.all_participants.state.selected();
+    let drawable = app.drawable();
+
+    // Counted in drawable participants, with the selected one at a whole number.
+    let position = drawable
+        .iter()
+        .position(|&i| Some(i) == selected)
+        .map(|slot| slot as f64 + app.spin_fraction(Instant::now()));
+
+    let (highlight, caption) = match (&app.spin_winner, selected) {
+        (Some(winner), _) => {
+            let mut caption = vec![Span::styled(
+                format!("🎉 {}", winner.name),
+                styles::winner(),
+            )];
+            if let Some(prize) = app.all_winners.last().and_then(|w| w.prize.as_ref()) {
+                caption.push(Span::styled(" wins ", styles::secondary()));
+                caption.push(Span::styled(prize.to_string(), styles::winner()));
+            }
+            caption.push(Span::raw(" 🎉"));
+            (styles::winner(), Line::from(caption))
+        }
+        (None, _) if app.still_standing().is_some() => (
+            styles::spin(),
+            Line::from(Span::styled(
+                format!("{} still standing", drawable.len()),
+                styles::spin(),
+            )),
+        ),
+        (None, Some(index)) if app.is_spinning => (
+            styles::spin(),
+            Line::from(Span::styled(items[index].name.clone(), styles::spin())),
+        ),
+        _ => (
+            styles::action(),
+            match (app.current_prize(), app.prizes_exhausted()) {
+                (_, true) => Line::from("All prizes have been given away 🎁"),
+                (Some(prize), _) => Line::from(vec![
+                    Span::raw("Ready to roll 🎲 "),
+                    Span::styled("Up next ", styles::secondary()),
+                    Span::styled(prize.to_string(), styles::orange()),
+                ]),
+                (None, _) => Line::from("Ready to roll 🎲"),
+            },
+        ),
+    };
+
+    let caption_height = 2.min(inner.height);
+    let visual_area = Rect {
+        height: inner.height - caption_height,
+        ..inner
+    };
+
+    match app.visual {
+        Visual::Reel => frame.render_widget(
+            Reel::new(
+                drawable.iter().map(|&i| items[i].name.as_str()).collect(),
+                position.unwrap_or(0.0),
+            )
+            .style(styles::orange())
+            .highlight_style(highlight),
+            visual_area,
+        ),
+        _ => frame.render_widget(
+            Wheel::new(
+                drawable
+                    .iter()
+                    .map(|&i| Slice {
+                        label: &items[i].name,
+                        weight: items[i].tickets,
+                    })
+                    .collect(),
+                // The pointer rests in the middle of a slice.
+                position.map_or(0.0, |position| position + 0.5),
+            )
+            .style(styles::secondary())
+            .highlight_style(highlight),
+            visual_area,
+        ),
+    }
+    frame.render_widget(
+        Paragraph::new(caption).alignment(Alignment::Center),
+        Rect {
+            y: visual_area.bottom(),
+            height: caption_height,
+            ..inner
+        },
+    );
+
+    render_spin_buttons(app, frame, area);
+}
+
+/// Clickable "Spin" and "Reset" buttons, centred on the bottom border of the spin `modal`.
+fn render_spin_buttons<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, modal: Rect) {
+    // Searches and editors have the keyboard, so the draw waits until they are closed.
+    if app.input_mode != InputMode::Normal {
+        return;
+    }
+
+    let mut buttons = Vec::new();
+
+    if !app.is_spinning && app.eligible_count() > 0 && !app.prizes_exhausted() {
+        buttons.push((" Spin ", Target::SpinButton));
+    }
+    if app.spin_winner.is_some() {
+        buttons.push((" Reset ", Target::ResetButton));
+    }
+
+    const GAP: u16 = 2;
+    let width: u16 = buttons
+        .iter()
+        .map(|(label, _)| label.len() as u16 + GAP)
+        .sum::<u16>()
+        .saturating_sub(GAP);
+    if buttons.is_empty() || modal.height < 2 || modal.width < width + 2 {
+        return;
+    }
+
+    let mut x = modal.x + (modal.width - width) / 2;
+    let y = modal.bottom() - 1;
+
+    for (label, target) in buttons {
+        let button = Rect {
+            x,
+            y,
+            width: label.len() as u16,
+            height: 1,
+        };
+
+        frame.render_widget(
+            Paragraph::new(Span::styled(label, styles::action_highlight())),
+            button,
         );
+        app.hit_areas.add(button, target);
+        x += button.width + GAP;
+    }
+}
+
+/// Shows the seed commitment along the bottom of `area`.
+///
+/// The seed itself is only revealed once the session has ended.
+fn render_seed<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
+    let mut seed_text = vec![Line::from(vec![
+        Span::styled("🔒 Commitment ", styles::secondary()),
+        Span::raw(app.commitment()),
+    ])];
+
+    if app.seed_revealed {
+        seed_text.push(Line::from(vec![
+            Span::styled("🔑 Seed       ", styles::secondary()),
+            Span::raw(app.seed.to_hex()),
+        ]));
+    }
+
+    let height = seed_text.len() as u16;
+    if area.height <= height {
+        return;
+    }
+
+    let seed_area = Rect {
+        y: area.bottom() - height,
+        height,
+        ..area
+    };
+
+    frame.render_widget(
+        Paragraph::new(seed_text).alignment(Alignment::Center),
+        seed_area,
+    );
+}
+
+/// Full-screen view for projectors: no list or help, just the draw in block letters.
+pub fn render_presentation<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
+    let area = area.inner(&Margin {
+        vertical: 1,
+        horizontal: 2,
+    });
+
+    // The audience sees the commitment before the first draw and the seed at the end.
+    render_seed(app, frame, area);
+    let seed_height = if app.seed_revealed { 3 } else { 2 };
+    let area = Rect {
+        height: area.height.saturating_sub(seed_height),
+        ..area
+    };
 
     // State: Winner found
     if let Some(winner) = &app.spin_winner {
-        modal_text = Text::from(vec![
+        let mut caption = Vec::new();
+
+        if let Some(prize) = app.all_winners.last().and_then(|w| w.prize.as_ref()) {
+            caption.extend(vec![
+                Line::from(Span::styled("wins", styles::secondary())),
+                Line::from(Span::styled(prize.to_string(), styles::orange())),
+            ]);
+        }
+        caption.extend(vec![Line::from(""), Line::from("🎉🎉🎉")]);
+
+        render_headline(frame, area, &winner.name, styles::winner(), caption);
+        return;
+    }
+
+    // State: Last one standing
+    if let Some(standing) = app.still_standing() {
+        let headline = format!("{} left", standing);
+        let caption = vec![Line::from(Span::styled(
+            "Last one standing wins",
+            styles::secondary(),
+        ))];
+
+        render_headline(frame, area, &headline, styles::spin_highlight(), caption);
+        return;
+    }
+
+    // State: Spinning
+    if app.is_spinning {
+        let name = app
+            .all_participants
+            .get_selected()
+            .map(|participant| participant.name)
+            .unwrap_or_default();
+
+        render_headline(frame, area, &name, styles::spin_highlight(), Vec::new());
+        return;
+    }
+
+    // State: Ready
+    let mut text = Text::from(art(&app.branding.logo, styles::winner()));
+
+    if let Some(prize) = app.current_prize() {
+        text.extend(vec![
+            Line::from(Span::styled("Up next", styles::secondary())),
+            Line::from(Span::styled(prize.to_string(), styles::orange())),
             Line::from(""),
-            Line::from(winner.clone().to_string()),
+        ]);
+    } else if app.prizes_exhausted() {
+        text.extend(vec![
+            Line::from("All prizes have been given away 🎁"),
             Line::from(""),
-            Line::from("🎉🎉🎉"),
         ]);
-
-        modal_content = Paragraph::new(modal_text)
-            .alignment(Alignment::Center)
-            .block(
-                Block::default()
-                    .title("  The winner is  ")
-                    .title_alignment(Alignment::Center)
-                    .borders(Borders::ALL)
-                    .border_type(BorderType::Thick)
-                    .style(styles::winner()),
-            );
     }
 
-    let modal = create_modal(40, 50, area);
-    frame.render_widget(Clear, modal);
-    frame.render_widget(modal_content, modal);
+    text.extend(vec![Line::from(vec![
+        Span::styled("S", styles::key()),
+        Span::raw(" to spin · "),
+        Span::styled("F", styles::key()),
+        Span::raw(" to leave presentation mode"),
+    ])]);
+
+    render_centered(frame, area, text);
+}
+
+/// Shows `headline` in block letters, as big as fits, with `caption` underneath, both
+/// together in the middle of `area`.
+fn render_headline<B: Backend>(
+    frame: &mut Frame<'_, B>,
+    area: Rect,
+    headline: &str,
+    style: Style,
+    caption: Vec<Line<'_>>,
+) {
+    // The caption sits one blank line below the headline.
+    let caption_height = match caption.len() as u16 {
+        0 => 0,
+        lines => lines + 1,
+    };
+
+    let headline = BigText::new(headline).style(style);
+    let height = headline.height(area.width, area.height.saturating_sub(caption_height));
+    let top = area.y + area.height.saturating_sub(height + caption_height) / 2;
+
+    frame.render_widget(
+        headline,
+        Rect {
+            y: top,
+            height,
+            ..area
+        },
+    );
+
+    let caption_top = (top + height + 1).min(area.bottom());
+    frame.render_widget(
+        Paragraph::new(caption).alignment(Alignment::Center),
+        Rect {
+            y: caption_top,
+            height: area.bottom() - caption_top,
+            ..area
+        },
+    );
+}
+
+/// Renders `text` centred horizontally and vertically in `area`.
+fn render_centered<B: Backend>(frame: &mut Frame<'_, B>, area: Rect, text: Text<'_>) {
+    let height = (text.height() as u16).min(area.height);
+
+    frame.render_widget(
+        Paragraph::new(text).alignment(Alignment::Center),
+        Rect {
+            y: area.y + (area.height - height) / 2,
+            height,
+            ..area
+        },
+    );
 }
 
 pub fn render_status<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
@@ -210,7 +921,16 @@ pub fn render_status<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area:
 
     let participant_count: usize = app.all_participants.items.len();
 
-    let mut status_text = Text::from(vec![
+    let mut status_text = Text::default();
+
+    if let Some(message) = &app.status_message {
+        status_text.extend(vec![
+            Line::from(Span::styled(message.clone(), styles::secondary())),
+            Line::from(""),
+        ]);
+    }
+
+    status_text.extend(vec![
         Line::from(vec![
             Span::styled(format!("✋ {}", participant_count), styles::orange()),
             Span::raw(" participants"),
@@ -218,15 +938,48 @@ pub fn render_status<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area:
         Line::from(""),
     ]);
 
-    let chance: f32 = 1_f32 / participant_count as f32;
+    // Show the odds of the selected participant, or of a single ticket otherwise.
+    let (chance, label) = match app.all_participants.get_selected() {
+        Some(participant) => (
+            app.chance(&participant),
+            format!(" chance for {}", participant.name),
+        ),
+        None => (
+            match app.eligible_tickets() {
+                0 => 0.0,
+                tickets => 1_f32 / tickets as f32,
+            },
+            " chance per ticket".to_string(),
+        ),
+    };
     let percentage = format!("{:.1}%", chance * 100_f32);
 
     status_text.extend(vec![Line::from(vec![
         Span::styled(format!("🍀 {}", percentage), styles::orange()),
-        Span::raw(" chance to win"),
+        Span::raw(label),
     ])]);
 
-    if app.is_spinning {
+    status_text.extend(vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("🎁 ", styles::orange()),
+            Span::raw(format!("Drawing {}", app.draw_mode)),
+        ]),
+        Line::from(vec![
+            Span::styled("🏁 ", styles::orange()),
+            Span::raw(format!("Next draw: {}", app.draw_style)),
+        ]),
+    ]);
+
+    if let Some(standing) = app.still_standing() {
+        status_text.extend(vec![
+            Line::from(""),
+            Line::from(vec![
+                Span::styled(format!("🎲 {}", standing), styles::orange()),
+                Span::raw(" still standing"),
+            ]),
+        ]);
+    } else if app.is_spinning {
         status_text.extend(vec![
             Line::from(""),
             Line::from(vec![
@@ -250,9 +1003,9 @@ pub fn render_status<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area:
     let winners: Vec<Line> = app
         .all_winners
         .iter()
-        .flat_map(|p| {
+        .flat_map(|w| {
             vec![
-                Line::from(Span::styled(p.to_string(), styles::winner())),
+                Line::from(Span::styled(w.to_string(), styles::winner())),
                 Line::from(""),
             ]
         })
@@ -268,46 +1021,151 @@ pub fn render_status<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area:
         split_pane[1],
     );
 
-    let help_text = Text::from(vec![
-        Line::from(vec![
-            Span::styled("S", styles::key()),
-            Span::raw(" to start the spin. "),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("R", styles::key()),
-            Span::raw(" to reset the spin."),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("⬇", styles::key()),
-            Span::raw("  / "),
-            Span::styled("⬆", styles::key()),
-            Span::raw("  to select list.\n"),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Backspace", styles::key()),
-            Span::raw(" to remove. "),
-        ]),
-        Line::from(""),
-        Line::from(vec![
-            Span::styled("Q", styles::key()),
-            Span::raw(" to quit. "),
-        ]),
-    ]);
+    // Only the keys that do something right now, so they all fit.
+    let idle = !app.is_spinning;
+    let selected = app.all_participants.state.selected().is_some();
+    let help_entries: Vec<(&[&str], &str)> = [
+        (&["S"][..], "spin", idle),
+        (&["R"], "reset", idle && app.spin_winner.is_some()),
+        (&["M"], "draw mode", idle),
+        (&["L"], "elimination", idle),
+        (&["A"], "add", idle),
+        (&["N"], "rename", idle && selected),
+        (&["P"], "paste", idle),
+        (&["/"], "search", idle),
+        (&["U"], "undo", idle),
+        (&["Ctrl-R"], "redo", idle),
+        (&["E"], "export", idle),
+        (&["V"], "visual", true),
+        (&["T"], "theme", true),
+        (&["F"], "present", true),
+        (&["Backspace"], "remove", idle && selected),
+        (&["⬇", "⬆"], "select", idle),
+        (&["Q"], "quit", true),
+    ]
+    .into_iter()
+    .filter(|&(_, _, shown)| shown)
+    .map(|(keys, description, _)| (keys, description))
+    .collect();
+
+    // Borders and padding on either side.
+    let width = split_pane[2]
+        .width
+        .saturating_sub(2 + styles::PADDING.left + styles::PADDING.right);
 
     frame.render_widget(
-        Paragraph::new(help_text).wrap(Wrap { trim: true }).block(
-            Block::default()
-                .title(" Help ")
-                .borders(Borders::ALL)
-                .padding(styles::PADDING),
-        ),
+        Paragraph::new(help_lines(&help_entries, width as usize))
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(" Help ")
+                    .borders(Borders::ALL)
+                    .padding(Padding {
+                        top: 0,
+                        bottom: 0,
+                        ..styles::PADDING
+                    }),
+            ),
         split_pane[2],
     );
 }
 
+/// Packs the Help entries, like "S spin", into as few lines of `width` as they fit on,
+/// without breaking an entry over two lines.
+fn help_lines<'a>(entries: &[(&[&'a str], &'a str)], width: usize) -> Vec<Line<'a>> {
+    let separator = Span::styled(" · ", styles::secondary());
+
+    let mut lines = Vec::new();
+    let mut line: Vec<Span> = Vec::new();
+
+    for (keys, description) in entries {
+        let entry = help_entry(keys, description);
+        let entry_width: usize = entry.iter().map(Span::width).sum();
+        let line_width: usize = line.iter().map(Span::width).sum();
+
+        if !line.is_empty() && line_width + separator.width() + entry_width > width {
+            lines.push(Line::from(std::mem::take(&mut line)));
+        }
+        if !line.is_empty() {
+            line.push(separator.clone());
+        }
+        line.extend(entry);
+    }
+    if !line.is_empty() {
+        lines.push(Line::from(line));
+    }
+
+    lines
+}
+
+/// An entry of the Help pane, like "U / Ctrl-R undo / redo".
+fn help_entry<'a>(keys: &[&'a str], description: &'a str) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+
+    for (i, key) in keys.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(" / "));
+        }
+        spans.push(Span::styled(*key, styles::key()));
+    }
+
+    spans.push(Span::raw(format!(" {}", description)));
+    spans
+}
+
+/// Text box of the participant editor, with the terminal cursor placed at the input cursor.
+pub fn render_input<B: Backend>(app: &mut App, frame: &mut Frame<'_, B>, area: Rect) {
+    let (title, hint) = match app.input_mode {
+        InputMode::Rename => ("  Rename participant  ", " Enter to save · Esc to cancel "),
+        InputMode::Paste => (
+            "  Paste participants, one per line  ",
+            " Ctrl-S to add all · Esc to cancel ",
+        ),
+        _ => ("  Add participant  ", " Enter to add · Esc to cancel "),
+    };
+
+    let block = Block::default()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .borders(Borders::ALL)
+        .border_type(BorderType::Thick)
+        .padding(styles::PADDING)
+        .style(styles::action());
+
+    let modal = match app.input_mode {
+        InputMode::Paste => create_modal(50, 60, area),
+        _ => create_modal(50, 20, area),
+    };
+    let inner = block.inner(modal);
+
+    // Keep the cursor in view when the text doesn't fit.
+    let before_cursor: Vec<&str> = app.input.before_cursor().split('\n').collect();
+    let row = before_cursor.len() as u16 - 1;
+    let column = Span::raw(*before_cursor.last().unwrap_or(&"")).width() as u16;
+    let scroll = (
+        row.saturating_sub(inner.height.saturating_sub(1)),
+        column.saturating_sub(inner.width.saturating_sub(1)),
+    );
+
+    let input = Paragraph::new(app.input.value.as_str())
+        .style(styles::orange())
+        .scroll(scroll)
+        .block(block);
+
+    frame.render_widget(Clear, modal);
+    frame.render_widget(input, modal);
+    frame.render_widget(
+        Paragraph::new(Span::styled(hint, styles::secondary())).alignment(Alignment::Center),
+        Rect {
+            y: modal.bottom().saturating_sub(1),
+            height: 1,
+            ..modal
+        },
+    );
+
+    frame.set_cursor(inner.x + column - scroll.1, inner.y + row - scroll.0);
+}
+
 // Modal window
 fn create_modal(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -0,0 +1,180 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::Rect,
+    style::{Color, Style},
+    symbols::Marker,
+    text::{Line as TextLine, Span},
+    widgets::{
+        canvas::{Canvas, Circle, Line, Points},
+        Widget,
+    },
+};
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+/// Radius of the wheel, leaving room for the pointer above it.
+const RADIUS: f64 = 0.85;
+
+/// How far out from the centre labels are placed, as a fraction of the radius.
+const LABEL_RADIUS: f64 = 0.6;
+
+/// A slice of the wheel.
+#[derive(Debug, Clone)]
+pub struct Slice<'a> {
+    pub label: &'a str,
+    /// Share of the wheel, relative to the other slices.
+    pub weight: u32,
+}
+
+/// Wheel of fortune drawn with braille dots, turning under a fixed pointer at the top.
+#[derive(Debug, Clone)]
+pub struct Wheel<'a> {
+    slices: Vec<Slice<'a>>,
+    /// Where the pointer is, counted in slices: 2.5 is the middle of the third slice.
+    position: f64,
+    style: Style,
+    highlight_style: Style,
+}
+
+impl<'a> Wheel<'a> {
+    pub fn new(slices: Vec<Slice<'a>>, position: f64) -> Self {
+        Self {
+            slices,
+            position,
+            style: Style::default(),
+            highlight_style: Style::default(),
+        }
+    }
+
+    /// Style of the outline and labels.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Style of the slice under the pointer.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+
+    /// Where each slice starts, as a fraction of a full turn, followed by 1.
+    fn boundaries(&self) -> Vec<f64> {
+        let total: u64 = self.slices.iter().map(|s| s.weight as u64).sum();
+
+        let mut boundaries = vec![0.0];
+        let mut covered = 0;
+        for slice in &self.slices {
+            covered += slice.weight as u64;
+            boundaries.push(match total {
+                0 => boundaries.len() as f64 / self.slices.len() as f64,
+                _ => covered as f64 / total as f64,
+            });
+        }
+        boundaries
+    }
+}
+
+impl Widget for Wheel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let count = self.slices.len();
+        if count == 0 || area.width < 4 || area.height < 4 {
+            return;
+        }
+
+        // Terminal cells are about twice as tall as wide; stretch the bounds to keep it round.
+        let aspect = area.width as f64 / (2.0 * area.height as f64);
+        let (x_max, y_max) = match aspect >= 1.0 {
+            true => (aspect, 1.0),
+            false => (1.0, 1.0 / aspect),
+        };
+        let cell_width = 2.0 * x_max / area.width as f64;
+        let cell_height = 2.0 * y_max / area.height as f64;
+
+        let boundaries = self.boundaries();
+        let current = (self.position.floor() as usize) % count;
+        let pointer = boundaries[current]
+            + self.position.fract() * (boundaries[current + 1] - boundaries[current]);
+
+        // Screen angle of a point `turn` of the way round the wheel.
+        let angle = |turn: f64| FRAC_PI_2 + (turn - pointer) * TAU;
+        let colour = |style: Style| style.fg.unwrap_or(Color::Reset);
+
+        // Braille dots are two across and four down per cell.
+        let highlighted: Vec<(f64, f64)> = {
+            let (start, end) = (boundaries[current], boundaries[current + 1]);
+            let (dx, dy) = (cell_width / 2.0, cell_height / 4.0);
+
+            (0..area.width as usize * 2)
+                .flat_map(|column| (0..area.height as usize * 4).map(move |row| (column, row)))
+                .map(|(column, row)| (-x_max + column as f64 * dx, -y_max + row as f64 * dy))
+                .filter(|&(x, y)| {
+                    if x.hypot(y) > RADIUS {
+                        return false;
+                    }
+                    let turn = ((y.atan2(x) - FRAC_PI_2) / TAU + pointer).rem_euclid(1.0);
+                    (start..end).contains(&turn) || (count == 1)
+                })
+                .collect()
+        };
+
+        let canvas = Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([-x_max, x_max])
+            .y_bounds([-y_max, y_max])
+            .paint(|ctx| {
+                ctx.draw(&Points {
+                    coords: &highlighted,
+                    color: colour(self.highlight_style),
+                });
+                ctx.layer();
+
+                ctx.draw(&Circle {
+                    x: 0.0,
+                    y: 0.0,
+                    radius: RADIUS,
+                    color: colour(self.style),
+                });
+
+                if count > 1 {
+                    for &boundary in &boundaries[..count] {
+                        ctx.draw(&Line {
+                            x1: 0.0,
+                            y1: 0.0,
+                            x2: RADIUS * angle(boundary).cos(),
+                            y2: RADIUS * angle(boundary).sin(),
+                            color: colour(self.style),
+                        });
+                    }
+                }
+
+                // Only label slices tall enough to hold a line of text.
+                let max_chars = (RADIUS * 0.7 / cell_width) as usize;
+                for (i, slice) in self.slices.iter().enumerate() {
+                    let share = boundaries[i + 1] - boundaries[i];
+                    if share * TAU * LABEL_RADIUS * RADIUS < 1.5 * cell_height && count > 1 {
+                        continue;
+                    }
+
+                    let label: String = slice.label.chars().take(max_chars).collect();
+                    let middle = angle(boundaries[i] + share / 2.0);
+                    let x = LABEL_RADIUS * RADIUS * middle.cos()
+                        - label.chars().count() as f64 * cell_width / 2.0;
+                    let y = LABEL_RADIUS * RADIUS * middle.sin();
+
+                    let style = match i == current {
+                        true => self.highlight_style,
+                        false => self.style,
+                    };
+                    ctx.print(x, y, TextLine::from(Span::styled(label, style)));
+                }
+
+                ctx.print(
+                    -cell_width / 2.0,
+                    (RADIUS + cell_height).min(y_max - cell_height / 2.0),
+                    TextLine::from(Span::styled("▼", self.highlight_style)),
+                );
+            });
+
+        canvas.render(area, buf);
+    }
+}
@@ -0,0 +1,299 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use raffle::app::{App, DrawMode, DrawStyle, InputMode};
+use raffle::data::{Participant, Prize};
+use raffle::handler::handle_key_events;
+use raffle::seed::Seed;
+use std::time::Duration;
+
+fn participants(names: &[&str]) -> Vec<Participant> {
+    names.iter().map(|name| Participant::new(*name)).collect()
+}
+
+/// Plays a spin out frame by frame on a simulated clock.
+fn spin_to_completion(app: &mut App) {
+    app.start_spin();
+
+    let mut now = app.spin_started;
+    while app.is_spinning {
+        now += Duration::from_millis(100);
+        app.spin_round(now);
+    }
+}
+
+#[test]
+fn winner_is_marked_in_place() {
+    let mut app = App::with_participants(participants(&["Alice", "Bob", "Mallory"]));
+
+    spin_to_completion(&mut app);
+
+    let winner = app.spin_winner.clone().expect("a winner is drawn");
+    assert!(winner.is_winner);
+    assert_eq!(winner.to_string(), format!("🎁 {}", winner.name));
+
+    let marked: Vec<_> = app
+        .all_participants
+        .items
+        .iter()
+        .filter(|p| p.is_winner)
+        .collect();
+    assert_eq!(marked, vec![&winner]);
+}
+
+#[test]
+fn without_replacement_never_draws_the_same_winner_twice() {
+    let mut app = App::with_participants(participants(&["Alice", "Bob", "Mallory", "DJB"]));
+    assert_eq!(app.draw_mode, DrawMode::WithoutReplacement);
+
+    for _ in 0..4 {
+        spin_to_completion(&mut app);
+    }
+
+    let mut names: Vec<_> = app
+        .all_winners
+        .iter()
+        .map(|w| w.participant.name.clone())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["Alice", "Bob", "DJB", "Mallory"]);
+    assert_eq!(app.eligible_count(), 0);
+
+    // Nobody is left to draw.
+    app.start_spin();
+    assert!(!app.is_spinning);
+}
+
+#[test]
+fn with_replacement_keeps_winners_in_the_pool() {
+    let mut app = App::with_participants(participants(&["Alice"]));
+    app.toggle_draw_mode();
+    assert_eq!(app.draw_mode, DrawMode::WithReplacement);
+
+    spin_to_completion(&mut app);
+    spin_to_completion(&mut app);
+
+    assert_eq!(app.all_winners.len(), 2);
+    assert_eq!(app.eligible_count(), 1);
+    assert!(app
+        .all_winners
+        .iter()
+        .all(|w| w.participant.name == "Alice"));
+}
+
+#[test]
+fn same_seed_draws_same_winners_regardless_of_selection() {
+    let seed = Seed::from_hex(&"ab".repeat(32)).unwrap();
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie"];
+
+    let mut replay = App::with_seed(participants(&names), seed);
+    let expected: Vec<_> = (0..3).filter_map(|_| replay.draw_to_completion()).collect();
+
+    let mut app = App::with_seed(participants(&names), seed);
+    app.all_participants.next();
+    app.all_participants.next();
+    let mut winners = Vec::new();
+    for _ in 0..3 {
+        spin_to_completion(&mut app);
+        winners.push(app.spin_winner.clone().unwrap());
+        // Moving the cursor between draws must not change the outcome.
+        app.all_participants.previous();
+    }
+
+    assert_eq!(winners, expected);
+}
+
+#[test]
+fn seed_round_trips_through_hex_and_commits_to_sha256() {
+    let seed = Seed::from_hex(&"00".repeat(32)).unwrap();
+    let list = vec![Participant::new("Alice"), Participant::parse("Bob x2")];
+
+    assert_eq!(Seed::from_hex(&seed.to_hex()).unwrap(), seed);
+    // sha256(seed || "Alice\t1\nBob\t2\n")
+    assert_eq!(
+        seed.commitment(&list),
+        "6d14a03959fd31b051ca4bb7aa4caa669b33f53cd2841038088b2b7dc6cc432f"
+    );
+    assert_ne!(seed.commitment(&list), seed.commitment(&list[..1]));
+    assert!(Seed::from_hex("not a seed").is_err());
+}
+
+#[test]
+fn seed_is_revealed_only_when_the_session_ends() {
+    let mut app = App::with_participants(participants(&["Alice", "Bob", "Mallory"]));
+    app.prizes = vec![Prize::parse("Book | 2").unwrap()];
+
+    // The commitment follows the list until the first draw, then stays as published.
+    let before = app.commitment();
+    app.all_participants.items.push(Participant::new("DJB"));
+    assert_ne!(app.commitment(), before);
+    let published = app.commitment();
+
+    spin_to_completion(&mut app);
+    assert!(!app.seed_revealed);
+    app.all_participants.items.push(Participant::new("Diffie"));
+    assert_eq!(app.commitment(), published);
+
+    // Giving away the last prize ends the session, and no more draws can be made.
+    spin_to_completion(&mut app);
+    assert!(app.seed_revealed);
+    app.prizes[0].quantity = 3;
+    app.start_spin();
+    assert!(!app.is_spinning);
+    assert_eq!(app.all_winners.len(), 2);
+
+    // Undo can't take the reveal back.
+    app.undo();
+    assert!(app.seed_revealed);
+    app.start_spin();
+    assert!(!app.is_spinning);
+}
+
+#[test]
+fn quitting_reveals_the_seed_once_something_was_drawn() {
+    let mut app = App::with_participants(participants(&["Alice", "Bob"]));
+    app.quit();
+    assert!(!app.seed_revealed);
+
+    let mut app = App::with_participants(participants(&["Alice", "Bob"]));
+    spin_to_completion(&mut app);
+    app.quit();
+    assert!(app.seed_revealed);
+}
+
+#[test]
+fn last_one_standing_draws_the_same_winners_as_a_spin() {
+    let seed = Seed::from_hex(&"cd".repeat(32)).unwrap();
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie", "Hellman"];
+
+    let mut replay = App::with_seed(participants(&names), seed);
+    let expected: Vec<_> = (0..3).filter_map(|_| replay.draw_to_completion()).collect();
+
+    let mut app = App::with_seed(participants(&names), seed);
+    app.draw_style = DrawStyle::Elimination;
+    let mut winners = Vec::new();
+    for round in 0..3 {
+        app.start_spin();
+        assert_eq!(app.still_standing(), Some(names.len() - round));
+
+        let mut now = app.spin_started;
+        let mut standing = names.len() - round;
+        while app.is_spinning {
+            now += Duration::from_millis(100);
+            app.spin_round(now);
+
+            // Knock-outs only ever go one way.
+            let left = app.still_standing().unwrap_or(1);
+            assert!(left <= standing);
+            standing = left;
+        }
+
+        let winner = app.spin_winner.clone().unwrap();
+        let record = app.all_winners.last().unwrap();
+        assert_eq!(record.eliminated.len(), names.len() - round - 1);
+        assert!(!record.eliminated.contains(&winner.name));
+        winners.push(winner);
+    }
+
+    assert_eq!(winners, expected);
+}
+
+#[test]
+fn restarting_or_resetting_a_running_spin_does_not_re_roll_the_winner() {
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie", "Hellman"];
+
+    for byte in 0..20u8 {
+        let seed = Seed::from_hex(&format!("{:02x}", byte).repeat(32)).unwrap();
+
+        let mut replay = App::with_seed(participants(&names), seed);
+        let expected: Vec<_> = (0..2).filter_map(|_| replay.draw_to_completion()).collect();
+
+        let mut app = App::with_seed(participants(&names), seed);
+        let mut winners = Vec::new();
+        for _ in 0..2 {
+            app.start_spin();
+            let started = app.spin_started;
+            app.spin_round(started + Duration::from_secs(1));
+
+            // Pressing S or R mid-spin leaves the draw alone.
+            app.start_spin();
+            app.reset_spin();
+            app.start_spin();
+            assert!(app.is_spinning);
+            assert_eq!(app.spin_started, started);
+
+            app.spin_round(started + app.spin_config.duration);
+            winners.push(app.spin_winner.clone().unwrap());
+        }
+
+        assert_eq!(winners, expected);
+    }
+}
+
+#[test]
+fn moving_the_selection_mid_spin_does_not_change_the_winner() {
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie", "Hellman"];
+
+    for byte in 0..20u8 {
+        let seed = Seed::from_hex(&format!("{:02x}", byte).repeat(32)).unwrap();
+
+        let mut replay = App::with_seed(participants(&names), seed);
+        let expected = replay.draw_to_completion();
+
+        let mut app = App::with_seed(participants(&names), seed);
+        app.start_spin();
+        let started = app.spin_started;
+        app.spin_round(started + Duration::from_secs(1));
+
+        for code in [KeyCode::Down, KeyCode::Down, KeyCode::Up, KeyCode::Esc] {
+            handle_key_events(KeyEvent::new(code, KeyModifiers::NONE), &mut app).unwrap();
+        }
+
+        app.spin_round(started + app.spin_config.duration);
+        assert_eq!(app.spin_winner, expected);
+    }
+}
+
+#[test]
+fn search_cannot_move_the_selection_or_mark_winners_mid_spin() {
+    let mut app = App::with_participants(participants(&["Alice", "Bob", "Mallory"]));
+    app.start_search();
+    app.all_participants.state.select(Some(0));
+    app.start_spin();
+    assert!(app.is_spinning);
+
+    app.select_match(true);
+    app.toggle_winner_mark();
+
+    assert_eq!(app.all_participants.state.selected(), Some(0));
+    assert!(app.all_participants.items.iter().all(|p| !p.is_winner));
+}
+
+#[test]
+fn editing_during_an_elimination_keeps_the_drawn_winner() {
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie", "Hellman"];
+    let winner_index = |app: &App| app.all_participants.items.iter().position(|p| p.is_winner);
+
+    for byte in 0..10u8 {
+        let seed = Seed::from_hex(&format!("{:02x}", byte).repeat(32)).unwrap();
+
+        let mut replay = App::with_seed(participants(&names), seed);
+        replay.draw_style = DrawStyle::Elimination;
+        replay.draw_to_completion().unwrap();
+
+        let mut app = App::with_seed(participants(&names), seed);
+        app.draw_style = DrawStyle::Elimination;
+        app.start_spin();
+        let started = app.spin_started;
+        app.spin_round(started + Duration::from_secs(1));
+
+        // A rename records a checkpoint while the elimination is still running.
+        app.all_participants.state.select(Some(0));
+        app.input_mode = InputMode::Rename;
+        app.input.insert_str("Ada");
+        app.submit_input();
+        assert!(app.still_standing().is_some());
+
+        app.spin_round(started + Duration::from_secs(60));
+        assert!(!app.is_spinning);
+        assert_eq!(winner_index(&app), winner_index(&replay));
+    }
+}
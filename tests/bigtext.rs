@@ -0,0 +1,34 @@
+use raffle::bigtext::{self, Size};
+
+#[test]
+fn danish_letters_have_their_own_glyphs() {
+    let unknown = bigtext::render("?", Size::Medium);
+
+    for letter in ["Æ", "Ø", "Å", "æ", "ø", "å"] {
+        assert_ne!(bigtext::render(letter, Size::Medium), unknown, "{}", letter);
+    }
+    assert_eq!(
+        bigtext::render("å", Size::Medium),
+        bigtext::render("Å", Size::Medium)
+    );
+    assert_eq!(
+        bigtext::render("É", Size::Medium),
+        bigtext::render("E", Size::Medium)
+    );
+}
+
+#[test]
+fn fit_picks_the_biggest_size_then_wraps_then_gives_up() {
+    let name = "Ada Lovelace";
+
+    let wide = bigtext::fit(name, bigtext::width(name, Size::Huge), 40).unwrap();
+    assert_eq!(wide, bigtext::render(name, Size::Huge));
+
+    // Too narrow for one line of large letters, but each word fits on its own.
+    let width = bigtext::width("Lovelace", Size::Large);
+    let wrapped = bigtext::fit(name, width, 11).unwrap();
+    assert_eq!(wrapped.len(), Size::Large.height() * 2 + 1);
+    assert!(wrapped.iter().all(|line| line.chars().count() <= width));
+
+    assert_eq!(bigtext::fit(name, 10, 40), None);
+}
@@ -0,0 +1,116 @@
+use raffle::app::App;
+use raffle::branding::Branding;
+use raffle::data::Participant;
+use raffle::styles;
+use raffle::ui;
+use ratatui::backend::TestBackend;
+use ratatui::style::Color;
+use ratatui::Terminal;
+use std::{fs, path::Path};
+
+/// The Home tab as plain text.
+fn home(branding: Branding, height: u16) -> String {
+    let mut app = App::with_participants(vec![Participant::new("Ada")]);
+    app.branding = branding;
+
+    let mut terminal = Terminal::new(TestBackend::new(100, height)).unwrap();
+    terminal.draw(|frame| ui::render(&mut app, frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            (0..100)
+                .map(|x| buffer.get(x, y).symbol.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn missing_fields_keep_the_default_branding() {
+    let branding = Branding::parse(
+        r##"
+        event_name = "Rust Oslo"
+        organiser = "Kari"
+        date = "12 June"
+        accent = "#ce422b"
+        "##,
+        Path::new(""),
+    )
+    .unwrap();
+
+    assert_eq!(
+        branding,
+        Branding {
+            event_name: "Rust Oslo".to_string(),
+            organiser: Some("Kari".to_string()),
+            date: Some("12 June".to_string()),
+            accent: Some(Color::Rgb(0xce, 0x42, 0x2b)),
+            mascot: Some(styles::CRAB.to_string()),
+            ..Branding::default()
+        }
+    );
+
+    assert!(Branding::parse("accent = \"rust\"", Path::new("")).is_err());
+    assert!(Branding::parse("colour = \"red\"", Path::new("")).is_err());
+}
+
+#[test]
+fn logo_and_mascot_are_read_next_to_the_branding_file() {
+    let dir = std::env::temp_dir().join(format!("raffle-branding-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("logo.txt"), "OSLO RUST\n").unwrap();
+    fs::write(dir.join("mascot.txt"), " (\\_/)\n (o.o)\n").unwrap();
+    fs::write(
+        dir.join("branding.toml"),
+        "event_name = \"Rust Oslo\"\nlogo_file = \"logo.txt\"\nmascot_file = \"mascot.txt\"\n",
+    )
+    .unwrap();
+
+    let branding = Branding::load(&dir.join("branding.toml"));
+    fs::remove_dir_all(&dir).unwrap();
+    let branding = branding.unwrap();
+
+    assert_eq!(branding.logo, "OSLO RUST\n");
+    assert_eq!(branding.mascot.as_deref(), Some(" (\\_/)\n (o.o)\n"));
+
+    let screen = home(branding.clone(), 40);
+    assert!(screen.contains("OSLO RUST"));
+    assert!(screen.contains("Made for the"));
+    assert!(screen.contains("Rust Oslo"));
+    assert!(screen.contains("(o.o)"));
+    assert!(!screen.contains("Copenhagen"));
+
+    // Without room for the mascot, the instructions stay on screen instead.
+    let screen = home(branding, 19);
+    assert!(!screen.contains("(o.o)"));
+    assert!(screen.contains("Press Tab to continue"));
+}
+
+#[test]
+fn default_branding_is_the_copenhagen_rust_community() {
+    let screen = home(Branding::default(), 40);
+
+    assert!(screen.contains("Copenhagen Rust Community"));
+    assert!(screen.contains("( Rapidly Assembled Ferris Fortune Locator Engine )"));
+    assert!(
+        screen.contains("\\) /  o o  \\ (/|"),
+        "Ferris is the default mascot"
+    );
+    assert!(screen.contains("\\  Copenhagen Rust Community  /"));
+}
+
+#[test]
+fn other_events_get_plain_ferris_or_no_mascot_at_all() {
+    let branding = Branding::parse("event_name = \"Rust Oslo\"", Path::new("")).unwrap();
+    let screen = home(branding, 40);
+    assert!(screen.contains("\\) /  o o  \\ (/"));
+    assert!(!screen.contains("Copenhagen"));
+
+    for setting in ["mascot_file = false", "mascot_file = \"\""] {
+        let branding = Branding::parse(setting, Path::new("")).unwrap();
+        assert_eq!(branding.mascot, None, "{}", setting);
+        assert!(!home(branding, 40).contains("o o"), "{}", setting);
+    }
+}
@@ -0,0 +1,65 @@
+use raffle::celebration::{Celebration, DURATION};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::Widget;
+use std::time::Duration;
+
+/// The celebration for `seed` as it looks `elapsed` into it, as plain text.
+fn snapshot(seed: u64, elapsed: Duration) -> String {
+    let area = Rect::new(0, 0, 40, 12);
+    let mut buffer = Buffer::empty(area);
+
+    let mut celebration = Celebration::new(seed);
+    celebration.advance_to(elapsed);
+    (&celebration).render(area, &mut buffer);
+
+    (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buffer.get(x, y).symbol.as_str())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+#[test]
+fn same_seed_gives_the_same_frames() {
+    for millis in [500, 1500, 3000] {
+        let elapsed = Duration::from_millis(millis);
+        assert_eq!(snapshot(7, elapsed), snapshot(7, elapsed));
+    }
+
+    assert_ne!(
+        snapshot(7, Duration::from_secs(1)),
+        snapshot(8, Duration::from_secs(1))
+    );
+}
+
+#[test]
+fn frames_do_not_depend_on_how_often_the_celebration_is_updated() {
+    let mut stepped = Celebration::new(7);
+    for millis in (0..1500).step_by(16).chain([1500]) {
+        stepped.advance_to(Duration::from_millis(millis));
+    }
+
+    let area = Rect::new(0, 0, 40, 12);
+    let mut buffer = Buffer::empty(area);
+    (&stepped).render(area, &mut buffer);
+
+    let mut expected = Buffer::empty(area);
+    let mut jumped = Celebration::new(7);
+    jumped.advance_to(Duration::from_millis(1500));
+    (&jumped).render(area, &mut expected);
+
+    assert_eq!(buffer, expected);
+}
+
+#[test]
+fn celebration_ends_after_its_duration() {
+    let mut celebration = Celebration::new(7);
+    assert!(!celebration.is_finished());
+
+    celebration.advance_to(DURATION);
+    assert!(celebration.is_finished());
+}
@@ -0,0 +1,87 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::dedup::{self, Duplicate, DuplicateKind};
+use raffle::seed::Seed;
+
+fn participants(lines: &[&str]) -> Vec<Participant> {
+    lines.iter().map(|line| Participant::parse(line)).collect()
+}
+
+#[test]
+fn fold_ignores_case_accents_and_spacing() {
+    assert_eq!(dedup::fold("  Søren  Ærø"), "soren aero");
+    assert_eq!(dedup::fold("José Müller"), "jose muller");
+    assert_eq!(dedup::fold("STRAßE"), "strasse");
+}
+
+#[test]
+fn levenshtein_counts_single_character_edits() {
+    assert_eq!(dedup::levenshtein("", ""), 0);
+    assert_eq!(dedup::levenshtein("kitten", "sitting"), 3);
+    assert_eq!(dedup::levenshtein("jon smith", "john smith"), 1);
+    assert_eq!(dedup::levenshtein("abc", ""), 3);
+}
+
+#[test]
+fn duplicates_are_paired_with_their_closest_earlier_entry() {
+    let list = participants(&[
+        "Søren",
+        "Jon Smith",
+        "soren",
+        "John Smith",
+        "Søren",
+        "Ada",
+        "Ida",
+    ]);
+
+    let found: Vec<_> = dedup::find_duplicates(&list)
+        .into_iter()
+        .map(|d| (d.duplicate, d.original, d.kind, d.merge))
+        .collect();
+
+    assert_eq!(
+        found,
+        vec![
+            (2, 0, DuplicateKind::CaseOrAccent, true),
+            (3, 1, DuplicateKind::Similar, false),
+            // An exact match wins over a closer entry that only differs in case.
+            (4, 0, DuplicateKind::Exact, true),
+        ]
+    );
+}
+
+#[test]
+fn merging_from_the_back_keeps_indices_and_the_highest_ticket_count() {
+    let mut list = participants(&["Ada", "Grace x3", "ada", "Linus", "ADA x2", "grace"]);
+    let duplicates = dedup::find_duplicates(&list);
+    let merges: Vec<&Duplicate> = duplicates.iter().collect();
+
+    dedup::merge(&mut list, &merges);
+
+    assert_eq!(
+        list.iter()
+            .map(|p| (p.name.as_str(), p.tickets))
+            .collect::<Vec<_>>(),
+        vec![("Ada", 2), ("Grace", 3), ("Linus", 1)]
+    );
+}
+
+#[test]
+fn confirmed_review_draws_like_verify_with_merged_duplicates() {
+    let seed = Seed::from_hex(&"5a".repeat(32)).unwrap();
+    let list = participants(&["Ada", "Grace", "ada", "Linus", "Søren", "soren", "Ken"]);
+
+    let mut app = App::with_seed(list.clone(), seed);
+    app.start_review();
+    app.confirm_review();
+
+    let mut merged = list;
+    assert_eq!(dedup::merge_suggested(&mut merged), 2);
+    assert_eq!(app.all_participants.items, merged);
+
+    let mut replay = App::with_seed(merged, seed);
+    let expected: Vec<_> = (0..3).filter_map(|_| replay.draw_to_completion()).collect();
+    let winners: Vec<_> = (0..3).filter_map(|_| app.draw_to_completion()).collect();
+
+    assert_eq!(winners, expected);
+}
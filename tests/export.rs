@@ -0,0 +1,104 @@
+use chrono::{Local, TimeZone};
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::export::{ExportFormat, Report, ReportWinner};
+use std::fs;
+
+fn report(seed: Option<&str>) -> Report {
+    let at = Local.with_ymd_and_hms(2025, 6, 12, 20, 15, 0).unwrap();
+
+    Report {
+        exported_at: at,
+        participant_count: 12,
+        draw_mode: "without replacement".to_string(),
+        seed: seed.map(str::to_string),
+        commitment: "c0ffee".to_string(),
+        winners: vec![
+            ReportWinner {
+                round: 1,
+                name: "Ada | Lovelace".to_string(),
+                prize: Some("Programming Rust".to_string()),
+                sponsor: Some("O'Reilly".to_string()),
+                drawn_at: at,
+                eliminated: Vec::new(),
+            },
+            ReportWinner {
+                round: 2,
+                name: "Grace".to_string(),
+                prize: None,
+                sponsor: None,
+                drawn_at: at,
+                eliminated: vec!["Linus".to_string(), "Ken, Jr".to_string()],
+            },
+        ],
+    }
+}
+
+#[test]
+fn csv_has_a_row_per_winner_with_the_session_details() {
+    let csv = report(Some("5eed")).to_csv().unwrap();
+    let drawn_at = report(None).winners[0].drawn_at.to_rfc3339();
+
+    assert_eq!(
+        csv,
+        format!(
+            "round,name,prize,sponsor,drawn_at,participant_count,seed,eliminated\n\
+             1,Ada | Lovelace,Programming Rust,O'Reilly,{0},12,5eed,\n\
+             2,Grace,,,{0},12,5eed,\"Linus; Ken, Jr\"\n",
+            drawn_at
+        )
+    );
+}
+
+#[test]
+fn markdown_escapes_pipes_and_only_shows_a_revealed_seed() {
+    let markdown = report(Some("5eed")).to_markdown();
+
+    assert!(markdown.starts_with(
+        "## Raffle results\n\n2 winners drawn without replacement among 12 participants on 2025-06-12.\n\n"
+    ));
+    assert!(markdown
+        .contains("| 1 | Ada \\| Lovelace | Programming Rust (thanks to O'Reilly) | 20:15 |\n"));
+    assert!(markdown.contains("| 2 | Grace |  | 20:15 |\n"));
+    assert!(markdown
+        .contains("Round 2 was last one standing. Knocked out, in order: Linus, Ken, Jr.\n"));
+    assert!(markdown.ends_with(
+        "Seed `5eed` (commitment `c0ffee`). Check the draw with `raffle verify --seed 5eed`.\n"
+    ));
+
+    let hidden = report(None).to_markdown();
+    assert!(!hidden.contains("Seed `"));
+    assert!(hidden.ends_with("Commitment `c0ffee`. The seed is revealed when the session ends.\n"));
+}
+
+#[test]
+fn in_app_export_uses_the_chosen_format() {
+    let path = std::env::temp_dir().join(format!("raffle-export-{}.txt", std::process::id()));
+    let mut app = App::with_participants(vec![Participant::new("Ada")]);
+    app.output = Some(path.clone());
+    app.output_format = Some(ExportFormat::Json);
+
+    app.export_results();
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(app.export_format(&path), ExportFormat::Json);
+    assert!(contents.starts_with('{'));
+}
+
+#[test]
+fn in_app_export_keeps_the_session_going() {
+    let path = std::env::temp_dir().join(format!("raffle-export-{}.md", std::process::id()));
+    let mut app = App::with_participants(vec![Participant::new("Ada"), Participant::new("Bob")]);
+    app.output = Some(path.clone());
+
+    app.draw_to_completion().unwrap();
+    app.export_results();
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert!(!app.seed_revealed);
+    assert!(!contents.contains(&app.seed.to_string()));
+    assert!(contents.contains(&app.commitment()));
+    assert!(app.draw_to_completion().is_some());
+}
@@ -0,0 +1,66 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::ui;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+/// The draw tab of `app` at `width` x `height`, as plain text.
+fn screen(app: &mut App, width: u16, height: u16) -> String {
+    app.select_tab(1);
+
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|frame| ui::render(app, frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| buffer.get(x, y).symbol.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn every_key_fits_in_the_help_pane() {
+    // After a draw with someone selected, every key does something.
+    let mut app = App::with_participants(vec![Participant::new("Ada"), Participant::new("Bob")]);
+    app.draw_to_completion().unwrap();
+    app.all_participants.state.select(Some(0));
+
+    for (width, height) in [(120, 40), (160, 50)] {
+        let screen = screen(&mut app, width, height);
+
+        for entry in [
+            "S spin",
+            "R reset",
+            "N rename",
+            "U undo",
+            "Ctrl-R redo",
+            "Backspace remove",
+            "Q quit",
+        ] {
+            assert!(
+                screen.contains(entry),
+                "{:?} is missing at {}x{}:\n{}",
+                entry,
+                width,
+                height,
+                screen
+            );
+        }
+    }
+}
+
+#[test]
+fn help_only_lists_what_works_during_a_spin() {
+    let mut app = App::with_participants(vec![Participant::new("Ada"), Participant::new("Bob")]);
+    app.start_spin();
+
+    let screen = screen(&mut app, 120, 40);
+    assert!(screen.contains("Q quit"));
+    assert!(screen.contains("F present"));
+    assert!(!screen.contains("S spin"));
+    assert!(!screen.contains("U undo"));
+}
@@ -0,0 +1,77 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::seed::Seed;
+
+fn app() -> App {
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie", "Hellman"];
+    let participants = names.into_iter().map(Participant::new).collect();
+    App::with_seed(participants, Seed::from_hex(&"5a".repeat(32)).unwrap())
+}
+
+fn winners(app: &App) -> Vec<String> {
+    app.all_winners
+        .iter()
+        .map(|w| w.participant.name.clone())
+        .collect()
+}
+
+#[test]
+fn undoing_a_draw_restores_the_winners_and_rewinds_the_draws() {
+    let mut app = app();
+    let first = app.draw_to_completion().unwrap();
+    let second = app.draw_to_completion().unwrap();
+
+    app.undo();
+    assert_eq!(winners(&app), vec![first.name.clone()]);
+    assert!(!app
+        .all_participants
+        .items
+        .iter()
+        .any(|p| p.name == second.name && p.is_winner));
+    assert_eq!(
+        app.status_message.as_deref(),
+        Some(&*format!("Undid draw of {}", second.name))
+    );
+
+    // The random stream is back where it was, so the same draw comes up again.
+    assert_eq!(app.draw_to_completion(), Some(second.clone()));
+    assert_eq!(winners(&app), vec![first.name, second.name]);
+}
+
+#[test]
+fn redo_reapplies_an_undone_draw() {
+    let mut app = app();
+    app.draw_to_completion().unwrap();
+    app.draw_to_completion().unwrap();
+    let drawn = winners(&app);
+
+    app.undo();
+    app.undo();
+    assert!(app.all_winners.is_empty());
+
+    app.redo();
+    app.redo();
+    assert_eq!(winners(&app), drawn);
+
+    // Redoing moved the random stream on too, so the next draw is the third of the session.
+    let mut replay = self::app();
+    let expected: Vec<_> = (0..3).filter_map(|_| replay.draw_to_completion()).collect();
+    assert_eq!(app.draw_to_completion(), Some(expected[2].clone()));
+
+    app.redo();
+    assert_eq!(app.status_message.as_deref(), Some("Nothing to redo"));
+}
+
+#[test]
+fn a_new_action_drops_what_could_be_redone() {
+    let mut app = app();
+    app.draw_to_completion().unwrap();
+    app.undo();
+
+    app.all_participants.state.select(Some(0));
+    app.remove_selected();
+    app.redo();
+
+    assert_eq!(app.status_message.as_deref(), Some("Nothing to redo"));
+    assert!(app.all_winners.is_empty());
+}
@@ -0,0 +1,201 @@
+use raffle::data::{self, DataError, Format, Participant};
+use std::{fs, path::PathBuf};
+
+/// Writes `contents` to a file of its own in the temp directory.
+fn write_csv(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("raffle-{}-{}.csv", name, std::process::id()));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+fn import(name: &str, contents: &str, include_guests: bool) -> Result<data::Import, DataError> {
+    let path = write_csv(name, contents);
+    let import = data::import_participants(&path, None, include_guests);
+    fs::remove_file(&path).unwrap();
+    import
+}
+
+fn names(import: &data::Import) -> Vec<(&str, u32)> {
+    import
+        .participants
+        .iter()
+        .map(|p| (p.name.as_str(), p.tickets))
+        .collect()
+}
+
+#[test]
+fn only_yes_rsvps_are_kept() {
+    let import = import(
+        "rsvp",
+        "Name,User ID,RSVP,Guests\nAda,1,Yes,0\nGrace,2,No,0\nLinus,3,Waitlist,1\nKen,4,yes,\n",
+        false,
+    )
+    .unwrap();
+
+    assert_eq!(names(&import), vec![("Ada", 1), ("Ken", 1)]);
+    assert_eq!(
+        import
+            .skipped
+            .iter()
+            .map(|row| row.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "line 3: Grace answered \"No\"",
+            "line 4: Linus answered \"Waitlist\""
+        ]
+    );
+}
+
+#[test]
+fn guests_get_entries_of_their_own_when_asked() {
+    let csv = "Name,RSVP,Guests\nAda,Yes,2\nGrace,Yes,0\n";
+
+    let without = import("guests-off", csv, false).unwrap();
+    assert_eq!(names(&without), vec![("Ada", 1), ("Grace", 1)]);
+
+    let with = import("guests-on", csv, true).unwrap();
+    assert_eq!(
+        names(&with),
+        vec![("Ada", 1), ("Ada +1", 1), ("Ada +2", 1), ("Grace", 1)]
+    );
+}
+
+#[test]
+fn bad_guest_and_ticket_counts_are_reported_but_keep_the_member() {
+    let import = import(
+        "bad-counts",
+        "Name,RSVP,Guests,Tickets\nAda,Yes,lots,3\nGrace,Yes,1,zero\nLinus,Yes,0,0\n",
+        true,
+    )
+    .unwrap();
+
+    assert_eq!(
+        names(&import),
+        vec![("Ada", 3), ("Grace", 1), ("Grace +1", 1), ("Linus", 1)]
+    );
+    assert_eq!(
+        import
+            .skipped
+            .iter()
+            .map(|row| row.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "line 2: guests of Ada left out, invalid count \"lots\"",
+            "line 3: Grace kept with 1 ticket, invalid count \"zero\"",
+            "line 4: Linus kept with 1 ticket, invalid count \"0\"",
+        ]
+    );
+}
+
+#[test]
+fn byte_order_mark_before_the_header_is_ignored() {
+    let import = import("bom", "\u{feff}Name,RSVP\nAda,Yes\n", false).unwrap();
+
+    assert_eq!(import.participants, vec![Participant::new("Ada")]);
+}
+
+#[test]
+fn missing_name_column_is_an_error() {
+    let error = import("no-name", "Member,RSVP\nAda,Yes\n", false).unwrap_err();
+
+    assert!(matches!(error, DataError::Invalid { line: 1, .. }));
+    assert!(error.to_string().ends_with("there is no \"Name\" column"));
+}
+
+#[test]
+fn format_can_be_forced_for_any_extension() {
+    let path = write_csv("forced", "Name,RSVP\nAda,Yes\nGrace,No\n");
+    let as_text = data::import_participants(&path, Some(Format::Text), false).unwrap();
+    let as_csv = data::import_participants(&path, Some(Format::MeetupCsv), false).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(as_text.participants.len(), 3);
+    assert_eq!(names(&as_csv), vec![("Ada", 1)]);
+}
+
+/// Writes `contents` to a file called `name` of its own in the temp directory.
+fn write_file(name: &str, contents: &[u8]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("raffle-{}-{}", std::process::id(), name));
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn missing_files_are_reported_as_not_found() {
+    for name in ["missing.txt", "missing.csv"] {
+        let path = std::env::temp_dir().join(format!("raffle-{}-{}", std::process::id(), name));
+
+        let error = data::import_participants(&path, None, false).unwrap_err();
+        assert!(
+            matches!(&error, DataError::NotFound(p) if *p == path),
+            "{}",
+            name
+        );
+        assert_eq!(
+            error.to_string(),
+            format!("{} does not exist", path.display())
+        );
+    }
+
+    let path = PathBuf::from("no-such-participants.txt");
+    assert!(matches!(
+        data::read_participants_from_file(&path),
+        Err(DataError::NotFound(_))
+    ));
+}
+
+#[test]
+fn invalid_utf8_names_the_line_it_starts_on() {
+    let path = write_file("latin1.txt", b"Ada\n# Comment\nS\xf8ren\nLinus\n");
+    let from_file = data::read_participants_from_file(&path);
+    let imported = data::import_participants(&path, None, false);
+    fs::remove_file(&path).unwrap();
+
+    for error in [from_file.unwrap_err(), imported.unwrap_err()] {
+        assert!(
+            matches!(error, DataError::InvalidEncoding { line: 3, .. }),
+            "{:?}",
+            error
+        );
+        assert!(error.to_string().contains("line 3 is not valid UTF-8"));
+    }
+
+    let path = write_file("latin1.csv", b"Name,RSVP\nAda,Yes\nS\xf8ren,Yes\n");
+    let error = data::import_participants(&path, None, false).unwrap_err();
+    fs::remove_file(&path).unwrap();
+    assert!(
+        matches!(error, DataError::InvalidEncoding { line: 3, .. }),
+        "{:?}",
+        error
+    );
+}
+
+#[test]
+fn files_without_participants_are_empty() {
+    for (name, contents) in [
+        ("empty.txt", ""),
+        ("comments.txt", "# Nobody yet\n\n   \n# Still nobody\n"),
+        ("header.csv", "Name,RSVP,Guests\n"),
+        ("declined.csv", "Name,RSVP,Guests\nAda,No,0\n"),
+    ] {
+        let path = write_file(name, contents.as_bytes());
+        let error = data::import_participants(&path, None, false).unwrap_err();
+        let from_file = name
+            .ends_with(".txt")
+            .then(|| data::read_participants_from_file(&path));
+        fs::remove_file(&path).unwrap();
+
+        assert!(
+            matches!(&error, DataError::Empty(p) if *p == path),
+            "{}",
+            name
+        );
+        assert_eq!(
+            error.to_string(),
+            format!("{} has no participants", path.display())
+        );
+        if let Some(from_file) = from_file {
+            assert!(matches!(from_file, Err(DataError::Empty(_))), "{}", name);
+        }
+    }
+}
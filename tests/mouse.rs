@@ -0,0 +1,78 @@
+use crossterm::event::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::handler::handle_mouse_events;
+use raffle::ui::{self, Target};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+fn draw(app: &mut App) {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| ui::render(app, frame)).unwrap();
+}
+
+fn click(app: &mut App, column: u16, row: u16) {
+    handle_mouse_events(
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        },
+        app,
+    );
+}
+
+/// First cell at which `target` was drawn.
+fn find(app: &App, target: Target) -> (u16, u16) {
+    (0..40)
+        .flat_map(|row| (0..120).map(move |column| (column, row)))
+        .find(|&(column, row)| app.hit_areas.hit(column, row) == Some(target))
+        .unwrap_or_else(|| panic!("{:?} is not on screen", target))
+}
+
+#[test]
+fn clicks_switch_tabs_select_participants_and_press_buttons() {
+    let mut app = App::with_participants(
+        ["Ada", "Grace", "Linus"]
+            .into_iter()
+            .map(Participant::new)
+            .collect(),
+    );
+
+    draw(&mut app);
+    let (column, row) = find(&app, Target::Tab(1));
+    click(&mut app, column, row);
+    assert_eq!(app.tabs.active, 1);
+
+    draw(&mut app);
+    let (column, row) = find(&app, Target::Participant(2));
+    click(&mut app, column, row);
+    assert_eq!(app.all_participants.state.selected(), Some(2));
+
+    let (column, row) = find(&app, Target::SpinButton);
+    click(&mut app, column, row);
+    assert!(app.is_spinning);
+}
+
+#[test]
+fn spin_button_is_gone_while_searching() {
+    let mut app = App::with_participants(
+        ["Ada", "Grace", "Linus"]
+            .into_iter()
+            .map(Participant::new)
+            .collect(),
+    );
+    app.select_tab(1);
+    draw(&mut app);
+    let (column, row) = find(&app, Target::SpinButton);
+
+    app.start_search();
+    draw(&mut app);
+    assert!(!(0..40).any(
+        |row| (0..120).any(|column| app.hit_areas.hit(column, row) == Some(Target::SpinButton))
+    ));
+
+    click(&mut app, column, row);
+    assert!(!app.is_spinning);
+}
@@ -0,0 +1,41 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::ui;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+/// The presentation view as plain text.
+fn screen(app: &mut App) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| ui::render(app, frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    (0..40)
+        .map(|y| {
+            (0..120)
+                .map(|x| buffer.get(x, y).symbol.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn presentation_shows_the_commitment_and_then_the_seed() {
+    let mut app = App::with_participants(vec![Participant::new("Ada"), Participant::new("Bob")]);
+    app.presenting = true;
+
+    let before = screen(&mut app);
+    assert!(before.contains(&app.commitment()));
+    assert!(!before.contains(&app.seed.to_hex()));
+
+    app.draw_to_completion().unwrap();
+    let drawn = screen(&mut app);
+    assert!(drawn.contains(&app.commitment()));
+    assert!(!drawn.contains(&app.seed.to_hex()));
+
+    app.quit();
+    let ended = screen(&mut app);
+    assert!(ended.contains(&app.commitment()));
+    assert!(ended.contains(&app.seed.to_hex()));
+}
@@ -0,0 +1,79 @@
+use raffle::app::App;
+use raffle::data::{Participant, Prize};
+
+fn prize(title: &str, quantity: usize, sponsor: Option<&str>) -> Prize {
+    Prize {
+        title: title.to_string(),
+        quantity,
+        sponsor: sponsor.map(str::to_string),
+    }
+}
+
+fn app(prizes: &[&str]) -> App {
+    let names = ["Alice", "Bob", "Mallory", "DJB", "Diffie", "Hellman"];
+    let mut app = App::with_participants(names.into_iter().map(Participant::new).collect());
+    app.prizes = prizes
+        .iter()
+        .map(|line| Prize::parse(line).unwrap())
+        .collect();
+    app
+}
+
+#[test]
+fn catalog_lines_are_parsed() {
+    assert_eq!(
+        Prize::parse("Rust for Rustaceans"),
+        Ok(prize("Rust for Rustaceans", 1, None))
+    );
+    assert_eq!(
+        Prize::parse("  Programming Rust | 2 | O'Reilly "),
+        Ok(prize("Programming Rust", 2, Some("O'Reilly")))
+    );
+    assert_eq!(
+        Prize::parse("Sticker pack |  | "),
+        Ok(prize("Sticker pack", 1, None))
+    );
+    assert_eq!(
+        Prize::parse("Book | two"),
+        Err("invalid quantity \"two\" for Book".to_string())
+    );
+}
+
+#[test]
+fn draws_move_through_the_catalog_in_order() {
+    let mut app = app(&["Programming Rust | 2 | O'Reilly", "Rust for Rustaceans"]);
+
+    let mut awarded = Vec::new();
+    while let Some(prize) = app.current_prize().cloned() {
+        app.draw_to_completion();
+        assert_eq!(app.all_winners.last().unwrap().prize.as_ref(), Some(&prize));
+        awarded.push(prize.title);
+    }
+
+    assert_eq!(
+        awarded,
+        vec![
+            "Programming Rust",
+            "Programming Rust",
+            "Rust for Rustaceans"
+        ]
+    );
+    assert!(app.prizes_exhausted());
+
+    // Nothing is drawn once every prize is given away.
+    assert_eq!(app.draw_to_completion(), None);
+    assert_eq!(app.all_winners.len(), 3);
+}
+
+#[test]
+fn a_prize_listed_twice_is_given_away_twice() {
+    let mut app = app(&["Book | 1", "Book | 1"]);
+
+    app.draw_to_completion();
+    assert!(!app.prizes_exhausted());
+    app.draw_to_completion();
+    assert!(app.prizes_exhausted());
+
+    let indices: Vec<_> = app.all_winners.iter().map(|w| w.prize_index).collect();
+    assert_eq!(indices, vec![Some(0), Some(1)]);
+}
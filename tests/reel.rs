@@ -0,0 +1,95 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::reel::Reel;
+use raffle::seed::Seed;
+use raffle::spin::Visual;
+use raffle::ui;
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::Widget;
+use ratatui::Terminal;
+
+fn render(names: Vec<&str>, position: f64) -> Buffer {
+    let area = Rect::new(0, 0, 30, 11);
+    let mut buffer = Buffer::empty(area);
+    Reel::new(names, position)
+        .style(Style::default().fg(Color::White))
+        .highlight_style(Style::default().fg(Color::Red))
+        .render(area, &mut buffer);
+    buffer
+}
+
+fn row(buffer: &Buffer, y: u16) -> String {
+    (buffer.area.left()..buffer.area.right())
+        .map(|x| buffer.get(x, y).symbol.as_str())
+        .collect()
+}
+
+/// The row marked with the payline arrows.
+fn payline(buffer: &Buffer) -> u16 {
+    (buffer.area.top()..buffer.area.bottom())
+        .find(|&y| row(buffer, y).starts_with('▶'))
+        .expect("the payline is drawn")
+}
+
+#[test]
+fn the_name_on_the_payline_is_highlighted() {
+    let names = vec!["Ada", "Bob", "Cyd", "Dan"];
+    let buffer = render(names, 2.0);
+
+    let y = payline(&buffer);
+    assert_eq!(y, 5);
+    let line = row(&buffer, y);
+    assert!(line.starts_with('▶') && line.ends_with('◀'), "{}", line);
+    assert_eq!(line.trim_matches(['▶', '◀', ' ']), "Cyd");
+
+    let x = row(&buffer, y).find('C').unwrap() as u16 - 2;
+    let cell = buffer.get(x, y);
+    assert_eq!(cell.fg, Color::Red);
+    assert!(cell.modifier.contains(Modifier::BOLD));
+
+    // Neighbours sit two rows away on either side, wrapping around the reel.
+    assert_eq!(row(&buffer, y - 2).trim(), "Bob");
+    assert_eq!(row(&buffer, y + 2).trim(), "Dan");
+    assert_eq!(row(&buffer, y + 4).trim(), "Ada");
+    assert_eq!(buffer.get(x, y - 2).fg, Color::White);
+}
+
+#[test]
+fn halfway_between_names_the_payline_is_empty() {
+    let buffer = render(vec!["Ada", "Bob", "Cyd", "Dan"], 1.5);
+    let y = payline(&buffer);
+
+    assert_eq!(row(&buffer, y).trim_matches(['▶', '◀', ' ']), "");
+    assert_eq!(row(&buffer, y - 1).trim(), "Bob");
+    assert_eq!(row(&buffer, y + 1).trim(), "Cyd");
+}
+
+#[test]
+fn the_reel_stops_with_the_winner_on_the_payline() {
+    let names = ["Ada", "Bob", "Cyd", "Dan", "Eve"];
+
+    for byte in 0..5u8 {
+        let mut app = App::with_seed(
+            names.into_iter().map(Participant::new).collect(),
+            Seed::from_hex(&format!("{:02x}", byte).repeat(32)).unwrap(),
+        );
+        app.visual = Visual::Reel;
+        app.celebrate = false;
+        app.select_tab(1);
+        let winner = app.draw_to_completion().unwrap();
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|frame| ui::render(&mut app, frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let payline = (0..40)
+            .map(|y| row(buffer, y))
+            .find(|line| line.contains('▶'))
+            .expect("the payline is drawn");
+        let between = &payline[payline.find('▶').unwrap()..payline.find('◀').unwrap()];
+        assert_eq!(between.trim_start_matches('▶').trim(), winner.name);
+    }
+}
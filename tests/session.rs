@@ -0,0 +1,56 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::seed::Seed;
+use raffle::session::Session;
+use std::{fs, path::PathBuf};
+
+fn session_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("raffle-{}-{}.json", name, std::process::id()))
+}
+
+fn app() -> App {
+    let names = ["Alice", "Bob x2", "Mallory", "DJB", "Diffie"];
+    let seed = Seed::from_hex(&"3c".repeat(32)).unwrap();
+    App::with_seed(names.iter().map(|n| Participant::parse(n)).collect(), seed)
+}
+
+#[test]
+fn save_and_restore_picks_up_exactly_where_the_session_left_off() {
+    let path = session_file("round-trip");
+    let mut app = app();
+    app.draw_to_completion();
+    app.draw_to_completion();
+    app.all_participants.state.select(Some(3));
+
+    Session::new(&app).save(&path).unwrap();
+    let mut restored = Session::load(&path).unwrap().restore().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(restored.all_participants.items, app.all_participants.items);
+    assert_eq!(restored.all_participants.state.selected(), Some(3));
+    assert_eq!(restored.all_winners, app.all_winners);
+    assert_eq!(restored.spin_winner, app.spin_winner);
+    assert_eq!(restored.seed, app.seed);
+    assert_eq!(restored.commitment(), app.commitment());
+
+    // The random stream carries on from the same place.
+    assert_eq!(restored.draw_to_completion(), app.draw_to_completion());
+}
+
+#[test]
+fn unfinished_sessions_are_not_overwritten() {
+    let path = session_file("overwrite");
+    assert!(Session::check_overwrite(&path).is_ok());
+
+    let mut app = app();
+    app.session_file = Some(path.clone());
+    app.draw_to_completion();
+    let error = Session::check_overwrite(&path).unwrap_err();
+    assert!(error.to_string().contains("--resume"));
+
+    // Quitting on purpose ends the session, so a new one may take its place.
+    app.quit();
+    let check = Session::check_overwrite(&path);
+    fs::remove_file(&path).unwrap();
+    assert!(check.is_ok());
+}
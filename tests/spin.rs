@@ -0,0 +1,64 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::seed::Seed;
+use raffle::spin::{Easing, SpinConfig, MAX_ROTATIONS};
+use std::time::Duration;
+
+const EASINGS: [Easing; 3] = [Easing::Linear, Easing::EaseOut, Easing::Exponential];
+
+fn config(easing: Easing) -> SpinConfig {
+    SpinConfig {
+        easing,
+        duration: Duration::from_secs(6),
+        min_rotations: 3,
+    }
+}
+
+#[test]
+fn every_easing_lands_exactly_on_the_last_step_at_the_end() {
+    for easing in EASINGS {
+        let config = config(easing);
+
+        assert_eq!(config.steps_due(97, Duration::ZERO), 0, "{:?}", easing);
+        assert_eq!(config.position(97, config.duration), 97.0, "{:?}", easing);
+        assert_eq!(config.steps_due(97, config.duration), 97, "{:?}", easing);
+        assert_eq!(
+            config.steps_due(97, config.duration + Duration::from_secs(1)),
+            97,
+            "{:?}",
+            easing
+        );
+        assert!((easing.apply(1.0) - 1.0).abs() < 1e-12, "{:?}", easing);
+    }
+}
+
+#[test]
+fn steps_due_never_go_backwards_or_past_the_total() {
+    for easing in EASINGS {
+        let config = config(easing);
+        let mut previous = 0;
+
+        for millis in (0..=6000).step_by(50) {
+            let due = config.steps_due(50, Duration::from_millis(millis));
+            assert!(due >= previous, "{:?} at {}ms", easing, millis);
+            assert!(due <= 50, "{:?} at {}ms", easing, millis);
+            previous = due;
+        }
+        assert_eq!(previous, 50, "{:?}", easing);
+    }
+}
+
+#[test]
+fn huge_rotation_counts_are_capped_instead_of_overflowing() {
+    let participants = (0..10)
+        .map(|i| Participant::new(format!("Crab {}", i)))
+        .collect();
+    let mut app = App::with_seed(participants, Seed::from_hex(&"07".repeat(32)).unwrap());
+    app.spin_config.min_rotations = usize::MAX;
+
+    app.start_spin();
+
+    assert!(app.is_spinning);
+    assert!(app.spin_steps <= (MAX_ROTATIONS + 3) * 10);
+    assert!(app.spin_steps >= MAX_ROTATIONS * 10);
+}
@@ -0,0 +1,65 @@
+use raffle::theme::{Palette, Theme, Themes};
+use ratatui::style::Color;
+
+#[test]
+fn built_in_themes_are_cycled_in_order() {
+    let themes = Themes::default();
+
+    assert_eq!(
+        themes.names(),
+        vec!["dark", "light", "high-contrast", "colour-blind-safe"]
+    );
+    assert_eq!(themes.after("dark").name, "light");
+    assert_eq!(themes.after("colour-blind-safe").name, "dark");
+    assert_eq!(themes.after(&Theme::no_color().name).name, "dark");
+}
+
+#[test]
+fn user_themes_override_colours_of_their_base() {
+    let themes = Themes::parse(
+        r##"
+        [themes.projector]
+        base = "light"
+        action = "light blue"
+        winner = "#00aa00"
+        orange = 208
+
+        [themes.dark]
+        spin = "magenta"
+        "##,
+    )
+    .unwrap();
+
+    let projector = themes.get("projector").unwrap();
+    assert_eq!(
+        projector.palette,
+        Palette {
+            action: Color::LightBlue,
+            winner: Color::Rgb(0, 170, 0),
+            orange: Color::Indexed(208),
+            ..Palette::LIGHT
+        }
+    );
+
+    // A theme named after a built-in one replaces it in place.
+    assert_eq!(themes.get("dark").unwrap().palette.spin, Color::Magenta);
+    assert_eq!(themes.names()[0], "dark");
+    assert_eq!(themes.names().last(), Some(&"projector"));
+}
+
+#[test]
+fn mistakes_in_the_themes_file_are_reported() {
+    let unknown_colour = Themes::parse("[themes.bad]\naction = \"sky\"").unwrap_err();
+    assert!(unknown_colour.to_string().contains("unknown colour 'sky'"));
+
+    let unknown_base = Themes::parse("[themes.bad]\nbase = \"bad\"").unwrap_err();
+    assert!(unknown_base.to_string().contains("not a built-in theme"));
+
+    assert!(Themes::parse("[themes.bad]\nbackground = \"red\"").is_err());
+
+    let unknown_theme = Themes::default().find("solarized").unwrap_err();
+    assert_eq!(
+        unknown_theme.to_string(),
+        "unknown theme 'solarized', choose one of dark, light, high-contrast, colour-blind-safe"
+    );
+}
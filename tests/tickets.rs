@@ -0,0 +1,119 @@
+use raffle::app::{App, DrawMode};
+use raffle::data::Participant;
+use raffle::seed::Seed;
+use raffle::ui;
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+#[test]
+fn ticket_suffixes_are_parsed_from_the_end_of_the_line() {
+    let cases = [
+        ("Alice x3", "Alice", 3),
+        ("Bob ×2", "Bob", 2),
+        ("Carol X4  ", "Carol", 4),
+        ("Malcolm X", "Malcolm X", 1),
+        ("Alice x0", "Alice x0", 1),
+        ("Dave xtra", "Dave xtra", 1),
+        ("Eve", "Eve", 1),
+    ];
+
+    for (line, name, tickets) in cases {
+        assert_eq!(
+            Participant::parse(line),
+            Participant::with_tickets(name, tickets),
+            "{:?}",
+            line
+        );
+    }
+}
+
+#[test]
+fn each_ticket_belongs_to_one_eligible_participant() {
+    let mut app = App::with_participants(vec![
+        Participant::with_tickets("Alice", 1),
+        Participant::with_tickets("Bob", 2),
+        Participant::with_tickets("Carol", 3),
+    ]);
+
+    let holders: Vec<_> = (0..6).map(|t| app.eligible_holding_ticket(t)).collect();
+    assert_eq!(holders, [0, 1, 1, 2, 2, 2]);
+
+    // Once Bob has won, his tickets are out of the draw and Carol's come right after Alice's.
+    app.all_participants.items[1].is_winner = true;
+    let holders: Vec<_> = (0..4).map(|t| app.eligible_holding_ticket(t)).collect();
+    assert_eq!(holders, [0, 1, 1, 1]);
+}
+
+#[test]
+fn chance_follows_the_tickets() {
+    let mut app = App::with_participants(vec![
+        Participant::with_tickets("Alice", 1),
+        Participant::with_tickets("Bob", 3),
+    ]);
+    let alice = app.all_participants.items[0].clone();
+    let bob = app.all_participants.items[1].clone();
+
+    assert_eq!(app.eligible_tickets(), 4);
+    assert_eq!(app.chance(&alice), 0.25);
+    assert_eq!(app.chance(&bob), 0.75);
+
+    app.all_participants.items[1].is_winner = true;
+    let bob = app.all_participants.items[1].clone();
+    assert_eq!(app.chance(&alice), 1.0);
+    assert_eq!(app.chance(&bob), 0.0);
+
+    app.draw_mode = DrawMode::WithReplacement;
+    assert_eq!(app.chance(&bob), 0.75);
+}
+
+#[test]
+fn draws_land_on_the_holder_of_the_drawn_ticket() {
+    let mut wins = [0; 3];
+
+    for byte in 0..=255u8 {
+        let seed = Seed::from_hex(&format!("{:02x}", byte).repeat(32)).unwrap();
+        let mut app = App::with_seed(
+            vec![
+                Participant::with_tickets("Alice", 1),
+                Participant::with_tickets("Bob", 1),
+                Participant::with_tickets("Carol", 8),
+            ],
+            seed,
+        );
+
+        let winner = app.draw_to_completion().unwrap();
+        let index = app
+            .all_participants
+            .items
+            .iter()
+            .position(|p| p.name == winner.name)
+            .unwrap();
+        wins[index] += 1;
+    }
+
+    // Carol holds 80% of the tickets.
+    assert!(wins[2] > 180, "{:?}", wins);
+    assert!(wins[0] > 5 && wins[1] > 5, "{:?}", wins);
+}
+
+#[test]
+fn odds_stay_readable_once_everyone_has_won() {
+    let mut app = App::with_participants(vec![Participant::new("Alice"), Participant::new("Bob")]);
+    app.draw_to_completion().unwrap();
+    app.draw_to_completion().unwrap();
+    app.all_participants.state.select(None);
+    app.select_tab(1);
+    assert_eq!(app.eligible_tickets(), 0);
+
+    let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+    terminal.draw(|frame| ui::render(&mut app, frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let screen: String = buffer
+        .content
+        .iter()
+        .map(|cell| cell.symbol.as_str())
+        .collect();
+    assert!(screen.contains("0.0%"));
+    assert!(!screen.contains("inf%"));
+}
@@ -0,0 +1,101 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::event::EventHandler;
+use raffle::tui::{self, Tui};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+/// Stands in for stderr, keeping what the `Tui` writes after it is gone.
+#[derive(Debug, Clone, Default)]
+struct Output(Arc<Mutex<Vec<u8>>>);
+
+impl Output {
+    fn text(&self) -> String {
+        String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+fn active_tui(output: &Output) -> Tui<TestBackend, Output> {
+    let terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    let mut tui = Tui::with_output(terminal, EventHandler::new(250, 16), output.clone());
+    tui.enter().unwrap();
+    tui
+}
+
+fn assert_restored(output: &str) {
+    assert!(output.contains("\x1b[?1049l"), "leave alternate screen");
+    assert!(output.contains("\x1b[?1000l"), "disable mouse capture");
+    assert!(output.contains("\x1b[?25h"), "show cursor");
+}
+
+#[test]
+fn panic_while_drawing_restores_the_terminal() {
+    tui::install_panic_hook();
+    let output = Output::default();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        let mut tui = active_tui(&output);
+        assert!(
+            output.text().contains("\x1b[?1049h"),
+            "enter alternate screen"
+        );
+
+        let mut app = App::with_participants(vec![Participant::new("Ferris")]);
+        tui.draw(&mut app).unwrap();
+        panic!("boom");
+    }));
+
+    // The hook hands over to the default one and dropping the `Tui` doesn't panic again.
+    let payload = result.unwrap_err();
+    assert_eq!(payload.downcast_ref::<&str>(), Some(&"boom"));
+    assert_restored(&output.text());
+}
+
+#[test]
+fn exit_restores_the_terminal_once() {
+    let output = Output::default();
+    let mut tui = active_tui(&output);
+    assert!(tui.is_active());
+
+    tui.exit().unwrap();
+    assert!(!tui.is_active());
+    let restored = output.text();
+    assert_restored(&restored);
+
+    drop(tui);
+    assert_eq!(output.text(), restored);
+}
+
+#[test]
+fn inactive_tui_leaves_the_terminal_alone() {
+    let output = Output::default();
+    let terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    drop(Tui::with_output(
+        terminal,
+        EventHandler::new(250, 16),
+        output.clone(),
+    ));
+
+    assert_eq!(output.text(), "");
+}
+
+#[test]
+fn restore_sequence_leaves_alternate_screen_and_shows_cursor() {
+    let mut output = Vec::new();
+    tui::write_restore_sequence(&mut output).unwrap();
+
+    assert_restored(&String::from_utf8(output).unwrap());
+}
@@ -0,0 +1,173 @@
+use raffle::app::App;
+use raffle::data::Participant;
+use raffle::spin::Visual;
+use raffle::styles;
+use raffle::ui;
+use raffle::wheel::{Slice, Wheel};
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::Widget;
+use ratatui::Terminal;
+
+const HIGHLIGHT: Color = Color::Red;
+
+fn render(slices: Vec<Slice>, position: f64) -> Buffer {
+    let area = Rect::new(0, 0, 60, 30);
+    let mut buffer = Buffer::empty(area);
+    Wheel::new(slices, position)
+        .style(Style::default().fg(Color::White))
+        .highlight_style(Style::default().fg(HIGHLIGHT))
+        .render(area, &mut buffer);
+    buffer
+}
+
+/// Cells drawn in `colour`, as (column, row).
+fn cells(buffer: &Buffer, colour: Color) -> Vec<(u16, u16)> {
+    let area = buffer.area;
+    (area.top()..area.bottom())
+        .flat_map(|y| (area.left()..area.right()).map(move |x| (x, y)))
+        .filter(|&(x, y)| buffer.get(x, y).fg == colour && buffer.get(x, y).symbol != " ")
+        .collect()
+}
+
+/// Text drawn in `colour` on each row, with the rest left out.
+fn text(buffer: &Buffer, colour: Color) -> String {
+    text_in(buffer, buffer.area, colour)
+}
+
+/// Text drawn in `colour` on each row of `area`, with the rest left out.
+fn text_in(buffer: &Buffer, area: Rect, colour: Color) -> String {
+    (area.top()..area.bottom())
+        .map(|y| {
+            (area.left()..area.right())
+                .map(|x| buffer.get(x, y))
+                .filter(|cell| cell.fg == colour)
+                .map(|cell| cell.symbol.as_str())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn slices_are_sized_by_their_tickets() {
+    let slices = || {
+        vec![
+            Slice {
+                label: "Ada",
+                weight: 1,
+            },
+            Slice {
+                label: "Bob",
+                weight: 3,
+            },
+        ]
+    };
+
+    let ada = cells(&render(slices(), 0.5), HIGHLIGHT).len() as f64;
+    let bob = cells(&render(slices(), 1.5), HIGHLIGHT).len() as f64;
+
+    let ratio = bob / ada;
+    assert!((2.5..3.5).contains(&ratio), "{} vs {}", bob, ada);
+}
+
+#[test]
+fn the_slice_under_the_pointer_is_highlighted() {
+    let names = ["Ada", "Bob", "Cyd", "Dan"];
+
+    for (slot, name) in names.iter().enumerate() {
+        let slices = names
+            .iter()
+            .map(|&label| Slice { label, weight: 1 })
+            .collect();
+        let buffer = render(slices, slot as f64 + 0.5);
+
+        let highlighted = text(&buffer, HIGHLIGHT);
+        assert!(highlighted.contains(name), "{}:\n{}", name, highlighted);
+        for other in names.iter().filter(|&other| other != name) {
+            assert!(!highlighted.contains(other), "{}", other);
+        }
+
+        // Just inside the rim straight below the pointer, the wheel is the highlighted slice.
+        let pointer = cells(&buffer, HIGHLIGHT)
+            .into_iter()
+            .find(|&(x, y)| buffer.get(x, y).symbol == "▼")
+            .expect("the pointer is drawn");
+        let below = buffer.get(pointer.0, pointer.1 + 3);
+        assert_eq!(below.fg, HIGHLIGHT, "{}", name);
+    }
+}
+
+#[test]
+fn the_wheel_stops_with_the_winner_under_the_pointer() {
+    let names = ["Ada", "Bob", "Cyd", "Dan", "Eve"];
+
+    for byte in 0..5u8 {
+        let mut app = App::with_seed(
+            names.into_iter().map(Participant::new).collect(),
+            raffle::seed::Seed::from_hex(&format!("{:02x}", byte).repeat(32)).unwrap(),
+        );
+        app.visual = Visual::Wheel;
+        app.celebrate = false;
+        app.select_tab(1);
+        let winner = app.draw_to_completion().unwrap();
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|frame| ui::render(&mut app, frame)).unwrap();
+
+        // Right of the participant list and above the caption that names the winner.
+        let wheel = Rect::new(31, 5, 89, 15);
+        let colour = styles::winner().fg.unwrap();
+        let highlighted = text_in(terminal.backend().buffer(), wheel, colour);
+        assert!(!highlighted.contains('🎉'), "{}", highlighted);
+        assert!(highlighted.contains(&winner.name), "{}", winner.name);
+        for other in names.iter().filter(|&&other| other != winner.name) {
+            assert!(!highlighted.contains(other), "{} is highlighted", other);
+        }
+    }
+}
+
+#[test]
+fn drawable_keeps_the_winner_until_the_next_spin() {
+    let mut app = App::with_participants(["Ada", "Bob", "Cyd"].map(Participant::new).to_vec());
+    assert_eq!(app.drawable(), [0, 1, 2]);
+
+    let winner = app.draw_to_completion().unwrap();
+    let index = app
+        .all_participants
+        .items
+        .iter()
+        .position(|p| p.name == winner.name)
+        .unwrap();
+    assert_eq!(app.drawable(), [0, 1, 2]);
+
+    app.reset_spin();
+    let others: Vec<_> = (0..3).filter(|&i| i != index).collect();
+    assert_eq!(app.drawable(), others);
+}
+
+#[test]
+fn spin_fraction_stays_between_steps() {
+    let mut app = App::with_participants(["Ada", "Bob", "Cyd"].map(Participant::new).to_vec());
+    assert_eq!(app.spin_fraction(std::time::Instant::now()), 0.0);
+
+    app.start_spin();
+    let started = app.spin_started;
+    for millis in (0..6000).step_by(125) {
+        let now = started + std::time::Duration::from_millis(millis);
+        app.spin_round(now);
+        let fraction = app.spin_fraction(now);
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "{} at {}ms",
+            fraction,
+            millis
+        );
+    }
+
+    app.spin_round(started + app.spin_config.duration);
+    assert!(!app.is_spinning);
+    assert_eq!(app.spin_fraction(started + app.spin_config.duration), 0.0);
+}